@@ -7,11 +7,78 @@ use concordium_std::*;
 use core::fmt::Debug;
 
 type ProjectId = String;
-type ProjectUri = String;
-type PublicKey = String;
+type Tag = String;
+type PublicKey = PublicKeyEd25519;
+
+/// Basis points representing 100%.
+const MAX_BASIS_POINTS: u16 = 10000;
+
+/// Max length of the project name.
+const MAX_PROFILE_NAME_LENGTH: usize = 64;
+/// Max length of the project token ticker.
+const MAX_PROFILE_TICKER_LENGTH: usize = 10;
+/// Max length of the project category and chain.
+const MAX_PROFILE_LABEL_LENGTH: usize = 32;
+
+/// Max number of owners of a project until admin configures it.
+const DEFAULT_MAX_OWNERS: u32 = 10;
+
+/// Reward points of the curator and the validator of a project reaching Whitelist until admin
+/// configures them.
+const DEFAULT_WHITELIST_REWARD_POINTS: u64 = 10;
+/// Reward points of the curator and the validator of a project whose closed sale can no longer be
+/// refunded, i.e. reaching TokenGenerated, until admin configures them.
+const DEFAULT_SALE_CLOSED_REWARD_POINTS: u64 = 20;
+
+/// Max number of Candidate projects curated by a curator until admin configures it.
+const DEFAULT_MAX_OPEN_CANDIDATES: u32 = 5;
+/// Max number of projects curated by a curator in a window until admin configures it.
+const DEFAULT_MAX_CURATIONS_PER_WINDOW: u32 = 3;
+/// Length of the curation rate limit window in milliseconds until admin configures it.
+const DEFAULT_CURATION_WINDOW_MILLIS: u64 = 24 * 60 * 60 * 1000;
+
+/// Max number of whitelist lottery entrants of a project, which is also the cap until admin
+/// configures a lower one. It keeps registration and the draw within the energy limit.
+const MAX_WHITELIST_ENTRANTS: u32 = 1000;
+
+/// Max length of a project id.
+const MAX_PROJECT_ID_LENGTH: usize = 64;
+/// Prefix of the project ids generated by this contract.
+const GENERATED_PROJECT_ID_PREFIX: &str = "project-";
+
+/// Max length of a project tag.
+const MAX_TAG_LENGTH: usize = 32;
+
+/// Max number of listener contracts notified of project changes.
+const MAX_LISTENERS: usize = 10;
+
+/// Tag of the `Nonce` event defined in CIS-3 standard.
+const NONCE_EVENT_TAG: u8 = 250;
+/// Tag of the `RewardsClaimed` event, outside the tags reserved by CIS standards.
+const REWARDS_CLAIMED_EVENT_TAG: u8 = 0;
+
+/// Entrypoints which can be invoked through `permit` function.
+const SUPPORTS_PERMIT_ENTRYPOINTS: [EntrypointName; 4] = [
+    EntrypointName::new_unchecked("add_token_addr"),
+    EntrypointName::new_unchecked("propose_vesting_schedule"),
+    EntrypointName::new_unchecked("propose_project_uri"),
+    EntrypointName::new_unchecked("propose_project_profile"),
+];
+
+/// Standards supported by this contract, queried through `supports` function as defined in
+/// CIS-0 standard.
+const SUPPORTS_STANDARDS: [&str; 2] = ["CIS-0", "CIS-3"];
+
+/// Metadata url of the project, along with an optional SHA-256 hash of the metadata document,
+/// following `MetadataUrl` defined in CIS-2 standard.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone)]
+struct ProjectUri {
+    url: String,
+    hash: Option<[u8; 32]>,
+}
 
 /// The state of the OVERLAY projects.
-#[derive(Serial, DeserialWithState, StateClone)]
+#[derive(Serial, DeserialWithState)]
 #[concordium(state_parameter = "S")]
 struct State<S> {
     /// Owner/Admin address of this contract module.
@@ -21,6 +88,34 @@ struct State<S> {
     user_contract_addr: ContractAddress,
     /// OVERLAY project data map.
     project: StateMap<ProjectId, ProjectState, S>,
+    /// Whitelist lottery registration map of each project.
+    whitelist: StateMap<ProjectId, WhitelistState, S>,
+    /// Nonce of each account signing `permit` messages.
+    nonces: StateMap<AccountAddress, u64, S>,
+    /// Append-only history of the project uri of each project.
+    metadata_history: StateMap<ProjectId, Vec<MetadataRecord>, S>,
+    /// Tags managed by admin, mapped to ids of the projects tagged with them.
+    tags: StateMap<Tag, Vec<ProjectId>, S>,
+    /// Sequence number of the last project id generated by this contract.
+    project_id_counter: u64,
+    /// Max number of owners of a project.
+    max_owners: u32,
+    /// Archived project data map, excluded from listings.
+    archive: StateMap<ProjectId, ProjectState, S>,
+    /// Contracts notified of every project status change, in order of registration.
+    listeners: Vec<Listener>,
+    /// Reward points of each curator and validator.
+    rewards: StateMap<AccountAddress, RewardState, S>,
+    /// Reward points accrued when a project reaches each status.
+    reward_points: RewardPoints,
+    /// Stats of the projects curated by each curator.
+    curator_stats: StateMap<AccountAddress, ProjectStats, S>,
+    /// Stats of the projects validated by each validator.
+    validator_stats: StateMap<AccountAddress, ProjectStats, S>,
+    /// Limits of the projects curated by each curator.
+    curation_limits: CurationLimits,
+    /// Curation activity of each curator, checked against the curation limits.
+    curator_quotas: StateMap<AccountAddress, CuratorQuota, S>,
 }
 
 /// The state of a single OVERLAY project.
@@ -33,6 +128,55 @@ struct ProjectState {
     seed_nft_addr: Option<ContractAddress>,
     sale_addr: Option<ContractAddress>,
     status: ProjectStatus,
+    token_generated_at: Option<Timestamp>,
+    listing_addr: Option<ContractAddress>,
+    listed_at: Option<Timestamp>,
+    vesting_addr: Option<ContractAddress>,
+    vesting_started_at: Option<Timestamp>,
+    vesting_schedule: Option<VestingSchedule>,
+    pending_vesting_schedule: Option<VestingSchedule>,
+    allocation_tiers: Vec<AllocationTier>,
+    signed_action_nonce: u64,
+    pub_keys: Vec<PubKeyRecord>,
+    pending_project_uri: Option<ProjectUri>,
+    profile: ProjectProfile,
+    pending_profile: Option<ProjectProfile>,
+    tags: Vec<Tag>,
+    /// Number of owner approvals required to apply an owner proposal.
+    owner_threshold: u32,
+    owner_proposal: Option<OwnerProposal>,
+    /// Id of the project which this project has been merged into.
+    merged_into: Option<ProjectId>,
+    /// Curator account which curated this project.
+    curated_by: Option<AccountAddress>,
+    /// Validator account which validated this project.
+    validated_by: Option<AccountAddress>,
+}
+
+/// Owner threshold of a project until its owners agree on another one, which is a majority of the
+/// owners so that no single owner of a project with multiple owners can replace the others.
+fn majority_threshold(owners: &[AccountAddress]) -> u32 {
+    owners.len() as u32 / 2 + 1
+}
+
+impl ProjectState {
+    /// Replace the owners, discarding the pending owner proposal and resetting the owner threshold
+    /// to a majority of the new owners.
+    fn replace_owners(&mut self, owners: Vec<AccountAddress>) {
+        self.owner_threshold = majority_threshold(&owners);
+        self.owners = owners;
+        self.owner_proposal = None;
+    }
+
+    /// Check that the project URI can be replaced with the inputted one. Once the project has
+    /// been validated, its metadata must stay verifiable, so the new URI needs a hash.
+    fn check_project_uri(&self, project_uri: &ProjectUri) -> ContractResult<()> {
+        ensure!(
+            self.status == ProjectStatus::Candidate || project_uri.hash.is_some(),
+            Error::MissingMetadataHash
+        );
+        Ok(())
+    }
 }
 
 /// Listing status of the project.
@@ -47,6 +191,269 @@ enum ProjectStatus {
     OnSale,
     /// Token sale is closed.
     SaleClosed,
+    /// Project token has been generated (TGE).
+    TokenGenerated,
+    /// Project token has been listed on a DEX.
+    Listed,
+    /// Project token is being released by a vesting contract.
+    Vesting,
+    /// Token sale has been aborted and the sale contract is refunding participants.
+    Refunding,
+    /// Refund has been completed. This is a terminal status.
+    Cancelled,
+}
+
+/// Unlock schedule of the project token.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone)]
+struct VestingSchedule {
+    /// No token is unlocked before this time.
+    cliff: Timestamp,
+    /// Pairs of unlock time and basis points unlocked at that time, in ascending order of time.
+    tranches: Vec<(Timestamp, u16)>,
+    /// Vesting contract address that releases the project token.
+    vesting_contract_addr: ContractAddress,
+}
+
+impl VestingSchedule {
+    /// Check that tranches are not empty, are in strictly ascending order of time, do not unlock
+    /// before the cliff and unlock 100% in total.
+    fn is_valid(&self) -> bool {
+        if self.tranches.is_empty() {
+            return false;
+        }
+        let mut total: u32 = 0;
+        let mut previous: Option<Timestamp> = None;
+        for (time, basis_points) in self.tranches.iter() {
+            if *time < self.cliff || *basis_points == 0 {
+                return false;
+            }
+            if let Some(previous) = previous {
+                if *time <= previous {
+                    return false;
+                }
+            }
+            previous = Some(*time);
+            total += u32::from(*basis_points);
+        }
+        total == u32::from(MAX_BASIS_POINTS)
+    }
+
+    /// Basis points of the project token unlocked at the inputted time.
+    fn unlocked_basis_points(&self, time: Timestamp) -> u16 {
+        if time < self.cliff {
+            return 0;
+        }
+        self.tranches
+            .iter()
+            .filter(|(tranche_time, _)| *tranche_time <= time)
+            .map(|(_, basis_points)| *basis_points)
+            .sum()
+    }
+}
+
+/// A project uri which a project had from `timestamp`, along with the account which changed it.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone)]
+struct MetadataRecord {
+    uri: ProjectUri,
+    timestamp: Timestamp,
+    changed_by: AccountAddress,
+}
+
+/// Structured descriptive fields of a project which other contracts can reason about.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone, Default)]
+struct ProjectProfile {
+    name: Option<String>,
+    /// Ticker of the project token, consisting of uppercase ASCII letters and digits.
+    ticker: Option<String>,
+    category: Option<String>,
+    /// Chain on which the project token is issued.
+    chain: Option<String>,
+    total_supply: Option<u64>,
+    /// SHA-256 hash of the project website url.
+    website_hash: Option<[u8; 32]>,
+}
+
+impl ProjectProfile {
+    /// Check that text fields are not empty and within their length limits, the ticker consists of
+    /// uppercase ASCII letters and digits, and total supply is not zero.
+    fn is_valid(&self) -> bool {
+        let is_valid_text = |text: &Option<String>, max_length: usize| {
+            text.as_ref()
+                .map_or(true, |text| !text.is_empty() && text.len() <= max_length)
+        };
+        is_valid_text(&self.name, MAX_PROFILE_NAME_LENGTH)
+            && is_valid_text(&self.ticker, MAX_PROFILE_TICKER_LENGTH)
+            && is_valid_text(&self.category, MAX_PROFILE_LABEL_LENGTH)
+            && is_valid_text(&self.chain, MAX_PROFILE_LABEL_LENGTH)
+            && self.ticker.as_ref().map_or(true, |ticker| {
+                ticker
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            })
+            && self.total_supply != Some(0)
+    }
+}
+
+/// New owners and owner threshold of a project proposed by one of its owners.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone)]
+struct OwnerProposal {
+    owners: Vec<AccountAddress>,
+    owner_threshold: u32,
+    /// Current owners who approved this proposal, including the proposer.
+    approvals: Vec<AccountAddress>,
+}
+
+/// How a failure of invoking a listener contract is handled.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone, Copy)]
+enum ListenerFailurePolicy {
+    /// The failure is ignored and the status change goes through.
+    Ignore,
+    /// The failure rejects the status change.
+    Revert,
+}
+
+/// A contract entrypoint notified of every project status change.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone)]
+struct Listener {
+    address: ContractAddress,
+    entrypoint: OwnedEntrypointName,
+    failure_policy: ListenerFailurePolicy,
+}
+
+/// Reward points of a curator or a validator.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone, Default)]
+struct RewardState {
+    /// Points accrued but not claimed yet.
+    pending: u64,
+    /// Points claimed so far.
+    claimed: u64,
+}
+
+/// Reward points accrued to the curator and the validator of a project reaching each status.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone)]
+struct RewardPoints {
+    whitelist: u64,
+    /// Accrued once the closed sale can no longer be refunded, i.e. on reaching TokenGenerated.
+    sale_closed: u64,
+}
+
+/// Counters of the projects curated or validated by an account, by how far they progressed.
+/// A project counts only for its first curator, recorded as `curated_by`.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone, Default)]
+struct ProjectStats {
+    /// Projects curated or validated by the account.
+    projects: u64,
+    /// Projects which reached Whitelist.
+    whitelisted: u64,
+    /// Projects whose token sale was closed and can no longer be refunded, i.e. which reached
+    /// TokenGenerated.
+    sold_out: u64,
+    /// Projects which were deleted as a Candidate or cancelled by a refund.
+    rejected: u64,
+}
+
+/// Limits of the projects curated by a curator through `curate_project`.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone)]
+struct CurationLimits {
+    /// Max number of Candidate projects curated by a curator.
+    max_open_candidates: u32,
+    /// Max number of projects curated by a curator in a window.
+    max_curations_per_window: u32,
+    /// Length of the window.
+    window: Duration,
+}
+
+/// Curation activity of a curator.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone)]
+struct CuratorQuota {
+    /// Projects curated by the curator which are still Candidate.
+    open_candidates: u32,
+    /// Start of the current window.
+    window_start: Timestamp,
+    /// Projects curated by the curator in the current window.
+    window_curations: u32,
+}
+
+/// Public key of a project with its validity period.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone)]
+struct PubKeyRecord {
+    pub_key: PublicKey,
+    /// The key is valid from this time.
+    activated_at: Timestamp,
+    /// The key is valid until this time. None while the key is active.
+    revoked_at: Option<Timestamp>,
+}
+
+/// Allocation cap of the token sale granted to accounts staking at least `min_stake`.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone)]
+struct AllocationTier {
+    min_stake: u64,
+    max_allocation: u64,
+}
+
+/// Whitelist lottery registration state of a single OVERLAY project.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone)]
+struct WhitelistState {
+    /// Maximum number of entrants, at most `MAX_WHITELIST_ENTRANTS`.
+    max_entrants: u32,
+    /// Accounts registered for the lottery in order of registration.
+    entrants: Vec<AccountAddress>,
+    /// Commit-reveal draw of the lottery.
+    lottery: Option<Lottery>,
+}
+
+impl Default for WhitelistState {
+    fn default() -> Self {
+        WhitelistState {
+            max_entrants: MAX_WHITELIST_ENTRANTS,
+            entrants: Vec::new(),
+            lottery: None,
+        }
+    }
+}
+
+/// Commit-reveal draw of the whitelist lottery of a single OVERLAY project.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone)]
+struct Lottery {
+    /// SHA-256 hash of the seed committed by admin.
+    seed_hash: [u8; 32],
+    /// Number of winners to be drawn.
+    winner_count: u32,
+    /// Registration is closed at this time.
+    registration_deadline: Timestamp,
+    /// Seed revealed by admin after the registration deadline.
+    seed: Option<[u8; 32]>,
+    /// Winners drawn from the entrants with the revealed seed.
+    winners: Vec<AccountAddress>,
+}
+
+/// Draw winners from the entrants by a partial Fisher-Yates shuffle where the `round`-th swap is
+/// driven by `sha256(seed || entrants_hash || round)`, so that anyone can reproduce the draw from
+/// chain data. `entrants_hash` is `sha256` of the entrants in order of registration, which is
+/// only fixed once registration closes, so the seed committed before then does not determine the
+/// winners on its own.
+fn draw_winners(
+    crypto_primitives: &impl HasCryptoPrimitives,
+    seed: &[u8; 32],
+    entrants: &[AccountAddress],
+    winner_count: u32,
+) -> Vec<AccountAddress> {
+    let entrants_bytes: Vec<u8> = entrants.iter().flat_map(|entrant| entrant.0).collect();
+    let entrants_hash = crypto_primitives.hash_sha2_256(&entrants_bytes);
+    let mut candidates = entrants.to_vec();
+    let count = (winner_count as usize).min(candidates.len());
+    for round in 0..count {
+        let mut message = seed.to_vec();
+        message.extend_from_slice(&entrants_hash.0);
+        message.extend_from_slice(&(round as u32).to_le_bytes());
+        let hash = crypto_primitives.hash_sha2_256(&message);
+        let mut random = [0u8; 8];
+        random.copy_from_slice(&hash.0[..8]);
+        let offset = u64::from_le_bytes(random) % (candidates.len() - round) as u64;
+        candidates.swap(round, round + offset as usize);
+    }
+    candidates.truncate(count);
+    candidates
 }
 
 /// The response schema for `overlay-users.view_user` function.
@@ -66,6 +473,23 @@ struct ViewUserParams {
     addr: AccountAddress,
 }
 
+/// The parameter schema for `overlay-staking.view_stake` function.
+/// The contract at `staking_contract_addr` must expose a `view_stake` entrypoint which accepts
+/// the serialized account address of the staker and can be invoked read-only.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewStakeParams {
+    addr: AccountAddress,
+}
+
+/// The response schema for `overlay-staking.view_stake` function.
+/// `view_stake` must return the staked amount of the account as a little endian u64, in the same
+/// unit as `AllocationTier::min_stake`. Any other return value rejects with
+/// `FailedInvokeStakingContractView` or a parse error.
+#[derive(Serial, Deserial, SchemaType, Clone)]
+struct StakeStateResponse {
+    amount: u64,
+}
+
 /// The parameter schema for `overlay-users.curate` function.
 /// For more information see https://github.com/overlaydao/overlay-users.
 #[derive(Serial, Deserial, SchemaType)]
@@ -82,6 +506,59 @@ struct ValidateParams {
     project_id: ProjectId,
 }
 
+/// The parameter schema for `overlay-users.remove_project` function.
+/// Only newer releases of overlay-users expose it, see the Deployment section of README.
+#[derive(Serial, Deserial, SchemaType)]
+struct RemoveProjectParams {
+    project_id: ProjectId,
+}
+
+/// The parameter schema for `overlay-users.merge_project` function.
+/// Only newer releases of overlay-users expose it, see the Deployment section of README.
+#[derive(Serial, Deserial, SchemaType)]
+struct MergeProjectParams {
+    from: ProjectId,
+    into: ProjectId,
+}
+
+/// The parameter schema for `overlay-users.update_project_status` function.
+/// Only newer releases of overlay-users expose it, see the Deployment section of README.
+#[derive(Serial, Deserial, SchemaType)]
+struct UpdateProjectStatusParams {
+    project_id: ProjectId,
+    status: ProjectStatus,
+}
+
+/// Kind of the project change notified to listener contracts.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType, Clone)]
+enum ProjectEvent {
+    /// The project has been registered as Candidate.
+    Created,
+    /// The project status has changed to the notified status.
+    StatusChanged,
+    /// The project has been moved to the archive.
+    Archived,
+    /// The project has been deleted.
+    Deleted,
+    /// The project has been merged into the project of the inputted id and archived.
+    Merged(ProjectId),
+}
+
+/// The parameter schema for the entrypoints of listener contracts.
+/// `status` is the current status of the project, or the last one if it has left the project map.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType)]
+struct ProjectNotification {
+    project_id: ProjectId,
+    status: ProjectStatus,
+    event: ProjectEvent,
+}
+
+/// The parameter schema for sale contract's `open_refund` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct OpenRefundParams {
+    project_id: ProjectId,
+}
+
 /// The parameter schema for `update_contract_state` function.
 #[derive(Serial, Deserial, SchemaType)]
 struct UpdateContractStateParams {
@@ -100,7 +577,8 @@ struct TransferAdminParams {
 /// The parameter schema for `curate_project` function.
 #[derive(Serial, Deserial, SchemaType)]
 struct CurateProjectParams {
-    project_id: ProjectId,
+    /// A new project id is generated by this contract if None.
+    project_id: Option<ProjectId>,
     project_uri: ProjectUri,
     owners: Vec<AccountAddress>,
 }
@@ -111,7 +589,8 @@ type ApplyCurateProjectParams = CurateProjectParams;
 #[derive(Serial, Deserial, SchemaType)]
 struct CurateProjectAdminParams {
     curator: AccountAddress,
-    project_id: ProjectId,
+    /// A new project id is generated by this contract if None.
+    project_id: Option<ProjectId>,
     project_uri: ProjectUri,
     owners: Vec<AccountAddress>,
 }
@@ -133,6 +612,51 @@ struct ValidateProjectAdminParams {
     token_addr: Option<ContractAddress>,
 }
 
+/// The parameter schema for `propose_project_uri` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ProposeProjectUriParams {
+    project_id: ProjectId,
+    project_uri: ProjectUri,
+}
+
+/// The parameter schema for `approve_project_uri` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ApproveProjectUriParams {
+    project_id: ProjectId,
+}
+
+/// The parameter schema for `reject_project_uri` function.
+type RejectProjectUriParams = ApproveProjectUriParams;
+
+/// The parameter schema for `propose_project_profile` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ProposeProjectProfileParams {
+    project_id: ProjectId,
+    profile: ProjectProfile,
+}
+
+/// The parameter schema for `approve_project_profile` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ApproveProjectProfileParams {
+    project_id: ProjectId,
+}
+
+/// The parameter schema for `add_tag` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct AddTagParams {
+    tag: Tag,
+}
+
+/// The parameter schema for `remove_tag` function.
+type RemoveTagParams = AddTagParams;
+
+/// The parameter schema for `set_project_tags` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetProjectTagsParams {
+    project_id: ProjectId,
+    tags: Vec<Tag>,
+}
+
 /// The parameter schema for `add_pub_key` function.
 #[derive(Serial, Deserial, SchemaType)]
 struct AddPubKeyParams {
@@ -140,6 +664,69 @@ struct AddPubKeyParams {
     pub_key: PublicKey,
 }
 
+/// The parameter schema for `rotate_pub_key` function.
+type RotatePubKeyParams = AddPubKeyParams;
+
+/// The parameter schema for `revoke_pub_key` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct RevokePubKeyParams {
+    project_id: ProjectId,
+}
+
+/// The parameter schema for `set_max_owners` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetMaxOwnersParams {
+    max_owners: u32,
+}
+
+/// The parameter schema for `add_listener` function.
+type AddListenerParams = Listener;
+
+/// The parameter schema for `remove_listener` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct RemoveListenerParams {
+    address: ContractAddress,
+    entrypoint: OwnedEntrypointName,
+}
+
+/// The parameter schema for `set_reward_points` function.
+type SetRewardPointsParams = RewardPoints;
+
+/// The parameter schema for `set_curation_limits` function.
+type SetCurationLimitsParams = CurationLimits;
+
+/// The parameter schema for `propose_owners` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ProposeOwnersParams {
+    project_id: ProjectId,
+    owners: Vec<AccountAddress>,
+    owner_threshold: u32,
+}
+
+/// The parameter schema for `approve_owners` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ApproveOwnersParams {
+    project_id: ProjectId,
+}
+
+/// The parameter schema for `archive_project` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ArchiveProjectParams {
+    project_id: ProjectId,
+}
+
+/// The parameter schema for `delete_project` function.
+type DeleteProjectParams = ArchiveProjectParams;
+
+/// The parameter schema for `merge_projects` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct MergeProjectsParams {
+    /// Id of the project which survives the merge.
+    keep: ProjectId,
+    /// Id of the duplicate project which is archived by the merge.
+    drop: ProjectId,
+}
+
 /// The parameter schema for `update_owners` function.
 #[derive(Serial, Deserial, SchemaType)]
 struct UpdateOwnersParams {
@@ -180,237 +767,1013 @@ struct CloseSaleParams {
     project_id: ProjectId,
 }
 
-/// The parameter schema for `upgrade` function.
-#[derive(Debug, Serialize, SchemaType)]
-struct UpgradeParams {
-    module: ModuleReference,
-    migrate: Option<(OwnedEntrypointName, OwnedParameter)>,
+/// The parameter schema for `generate_token` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct GenerateTokenParams {
+    project_id: ProjectId,
+    token_addr: ContractAddress,
 }
 
-/// The parameter schema for `view_project` function.
+/// The parameter schema for `list_token` function.
 #[derive(Serial, Deserial, SchemaType)]
-struct ViewProjectParams {
+struct ListTokenParams {
     project_id: ProjectId,
+    listing_addr: ContractAddress,
 }
 
-/// The response schema for `view_admin` function.
+/// The parameter schema for `start_vesting` function.
 #[derive(Serial, Deserial, SchemaType)]
-struct ViewAdminRes {
-    admin: AccountAddress,
-    staking_contract_addr: ContractAddress,
-    user_contract_addr: ContractAddress,
+struct StartVestingParams {
+    project_id: ProjectId,
+    vesting_addr: ContractAddress,
 }
 
-/// The response schema for `view_project` function.
-type ViewProjectResponse = ProjectState;
+/// The parameter schema for `propose_vesting_schedule` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ProposeVestingScheduleParams {
+    project_id: ProjectId,
+    schedule: VestingSchedule,
+}
 
-/// The response schema for `view_projects` function.
-type ViewProjectsResponse = Vec<(ProjectId, ProjectState)>;
+/// The parameter schema for `approve_vesting_schedule` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ApproveVestingScheduleParams {
+    project_id: ProjectId,
+}
 
-/// The response schema for `view_project_ids` function.
-type ViewProjectIdsResponse = Vec<ProjectId>;
+/// The parameter schema for `start_refund` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct StartRefundParams {
+    project_id: ProjectId,
+}
 
-/// Custom error definitions of OVERLAY projects smart contract.
-#[derive(Debug, PartialEq, Eq, Reject, Serialize, SchemaType)]
-enum Error {
-    #[from(ParseError)]
-    ParseParamsError,
-    InvalidCaller,
-    InvalidStatus,
-    ShouldNotBeTON,
-    OnlyAccount,
-    FailedInvokeUserContract,
-    FailedInvokeUserContractView,
-    ProjectHasBeenInitializedAlready,
-    ProjectNotFound,
+/// The parameter schema for `complete_refund` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct CompleteRefundParams {
+    project_id: ProjectId,
 }
 
-type ContractResult<A> = Result<A, Error>;
+/// The parameter schema for `set_whitelist_cap` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetWhitelistCapParams {
+    project_id: ProjectId,
+    max_entrants: u32,
+}
 
-/// The smart contract module init function.
-/// Although anyone can init this module, this function is expected to be called by OVERLAY team.
-#[init(contract = "overlay-projects", parameter = "InitParams")]
-fn contract_init<S: HasStateApi>(
-    ctx: &impl HasInitContext,
-    state_builder: &mut StateBuilder<S>,
-) -> InitResult<State<S>> {
-    let params: InitParams = ctx.parameter_cursor().get()?;
-    let state = State {
-        admin: ctx.init_origin(),
-        staking_contract_addr: params.staking_contract_addr,
-        user_contract_addr: params.user_contract_addr,
-        project: state_builder.new_map(),
-    };
-    Ok(state)
+/// The parameter schema for `register_whitelist` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct RegisterWhitelistParams {
+    project_id: ProjectId,
 }
 
-/// Update associated staking/user contract address.
-///
-/// Caller: current admin account.
-/// Reject if:
-/// * Caller is not the current admin account.
-#[receive(
-    contract = "overlay-projects",
-    name = "update_contract_state",
-    parameter = "UpdateContractStateParams",
-    mutable,
-    error = "Error"
-)]
-fn contract_update_contract_state<S: HasStateApi>(
-    ctx: &impl HasReceiveContext,
-    host: &mut impl HasHost<State<S>, StateApiType = S>,
-) -> ContractResult<()> {
-    let state = host.state_mut();
-    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    let params: UpdateContractStateParams = ctx.parameter_cursor().get()?;
-    state.staking_contract_addr = params.staking_contract_addr;
-    state.user_contract_addr = params.user_contract_addr;
-    Ok(())
+/// The parameter schema for `commit_lottery` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct CommitLotteryParams {
+    project_id: ProjectId,
+    seed_hash: [u8; 32],
+    winner_count: u32,
+    registration_deadline: Timestamp,
 }
 
-/// Transfer admin of this module to another account.
-///
-/// Caller: current admin account.
-/// Reject if:
-/// * Caller is not the current admin account.
-#[receive(
-    contract = "overlay-projects",
-    name = "transfer_admin",
-    parameter = "TransferAdminParams",
-    mutable,
-    error = "Error"
-)]
-fn contract_transfer_admin<S: HasStateApi>(
-    ctx: &impl HasReceiveContext,
-    host: &mut impl HasHost<State<S>, StateApiType = S>,
-) -> ContractResult<()> {
-    let state = host.state_mut();
-    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    let params: TransferAdminParams = ctx.parameter_cursor().get()?;
-    state.admin = params.admin;
-    Ok(())
+/// The parameter schema for `reveal_lottery` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct RevealLotteryParams {
+    project_id: ProjectId,
+    seed: [u8; 32],
+}
+
+/// The parameter schema for `set_allocation_tiers` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SetAllocationTiersParams {
+    project_id: ProjectId,
+    tiers: Vec<AllocationTier>,
+}
+
+/// Project action authorised by a signature of the project public key.
+#[derive(Serial, Deserial, SchemaType, Clone)]
+enum SignedProjectAction {
+    /// Propose a new project URI of the project, which is applied once approved by admin.
+    UpdateProjectUri(ProjectUri),
+    /// Update owners of the project. Not allowed while the project status is Candidate.
+    UpdateOwners(Vec<AccountAddress>),
+}
+
+/// The message signed by the project public key for `apply_signed_action` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct SignedActionMessage {
+    /// Address of this contract, so that the signature cannot be replayed on other contracts.
+    contract_address: ContractAddress,
+    project_id: ProjectId,
+    /// Must match with the current nonce of the project, so that the signature cannot be replayed.
+    nonce: u64,
+    action: SignedProjectAction,
+}
+
+/// The parameter schema for `apply_signed_action` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ApplySignedActionParams {
+    signature: SignatureEd25519,
+    message: SignedActionMessage,
+}
+
+/// The message signed by a project owner for `permit` function as defined in CIS-3 standard.
+#[derive(Serialize, SchemaType, Clone)]
+struct PermitMessage {
+    /// Address of this contract, so that the signature cannot be replayed on other contracts.
+    contract_address: ContractAddress,
+    /// Must match with the current nonce of the signer, so that the signature cannot be replayed.
+    nonce: u64,
+    /// The message is expired at this time.
+    timestamp: Timestamp,
+    /// One of `SUPPORTS_PERMIT_ENTRYPOINTS`.
+    entry_point: OwnedEntrypointName,
+    /// Serialized parameter of the entrypoint.
+    #[concordium(size_length = 2)]
+    payload: Vec<u8>,
+}
+
+/// The parameter schema for `permit` function.
+#[derive(Serialize, SchemaType)]
+struct PermitParams {
+    signature: AccountSignatures,
+    signer: AccountAddress,
+    message: PermitMessage,
+}
+
+/// The `Nonce` event logged by `permit` function as defined in CIS-3 standard.
+#[derive(Debug, PartialEq, Eq)]
+struct NonceEvent {
+    nonce: u64,
+    sponsoree: AccountAddress,
+}
+
+impl Serial for NonceEvent {
+    fn serial<W: Write>(&self, out: &mut W) -> Result<(), W::Err> {
+        NONCE_EVENT_TAG.serial(out)?;
+        self.nonce.serial(out)?;
+        self.sponsoree.serial(out)
+    }
+}
+
+/// The `RewardsClaimed` event logged by `claim_rewards` function.
+#[derive(Debug, PartialEq, Eq)]
+struct RewardsClaimedEvent {
+    account: AccountAddress,
+    points: u64,
+}
+
+impl Serial for RewardsClaimedEvent {
+    fn serial<W: Write>(&self, out: &mut W) -> Result<(), W::Err> {
+        REWARDS_CLAIMED_EVENT_TAG.serial(out)?;
+        self.account.serial(out)?;
+        self.points.serial(out)
+    }
+}
+
+/// The parameter schema for `upgrade` function.
+#[derive(Debug, Serialize, SchemaType)]
+struct UpgradeParams {
+    module: ModuleReference,
+    migrate: Option<(OwnedEntrypointName, OwnedParameter)>,
+}
+
+/// The parameter schema for `view_project` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewProjectParams {
+    project_id: ProjectId,
+}
+
+/// The parameter schema for `view_unlocked_percentage` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewUnlockedPercentageParams {
+    project_id: ProjectId,
+    time: Timestamp,
+}
+
+/// The parameter schema for `view_whitelist` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewWhitelistParams {
+    project_id: ProjectId,
+    addr: AccountAddress,
+}
+
+/// The response schema for `view_whitelist` function.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType)]
+struct ViewWhitelistResponse {
+    max_entrants: u32,
+    entrant_count: u32,
+    is_registered: bool,
+}
+
+/// The parameter schema for `view_lottery_result` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewLotteryResultParams {
+    project_id: ProjectId,
+    addr: AccountAddress,
+}
+
+/// The response schema for `view_lottery_result` function.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType)]
+struct ViewLotteryResultResponse {
+    seed_hash: [u8; 32],
+    seed: Option<[u8; 32]>,
+    is_entrant: bool,
+    is_winner: bool,
+}
+
+/// The parameter schema for `view_allocation` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewAllocationParams {
+    project_id: ProjectId,
+    addr: AccountAddress,
+}
+
+/// The response schema for `view_allocation` function.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType)]
+struct ViewAllocationResponse {
+    stake: u64,
+    /// Index of the allocation tier. None if the stake is below the lowest tier.
+    tier: Option<u32>,
+    max_allocation: u64,
+}
+
+/// The parameter schema for `view_project_metadata_history` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewProjectMetadataHistoryParams {
+    project_id: ProjectId,
+    /// Index of the first record to return.
+    from: u32,
+    /// Max number of records to return.
+    limit: u32,
+}
+
+/// The parameter schema for `view_projects_by_tag` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewProjectsByTagParams {
+    tag: Tag,
+    /// Index of the first project id to return.
+    from: u32,
+    /// Max number of project ids to return.
+    limit: u32,
+}
+
+/// The parameter schema for `view_pub_keys` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewPubKeysParams {
+    project_id: ProjectId,
+}
+
+/// The parameter schema for `supportsPermit` function.
+#[derive(Serialize, SchemaType)]
+struct SupportsPermitParams {
+    #[concordium(size_length = 2)]
+    queries: Vec<OwnedEntrypointName>,
+}
+
+/// Support result of a single query as defined in CIS-0 standard.
+#[derive(Debug, PartialEq, Eq, Serialize, SchemaType)]
+enum SupportResult {
+    NoSupport,
+    Support,
+}
+
+/// Identifier of a standard such as "CIS-3" as defined in CIS-0 standard.
+#[derive(Debug, Serialize, SchemaType)]
+struct StandardIdentifier {
+    #[concordium(size_length = 1)]
+    id: String,
+}
+
+/// The parameter schema for `supports` function.
+#[derive(Serialize, SchemaType)]
+struct SupportsParams {
+    #[concordium(size_length = 2)]
+    queries: Vec<StandardIdentifier>,
+}
+
+/// The response schema for `supportsPermit` function.
+#[derive(Debug, PartialEq, Eq, Serialize, SchemaType)]
+struct SupportsPermitResponse {
+    #[concordium(size_length = 2)]
+    results: Vec<SupportResult>,
+}
+
+/// The response schema for `supports` function.
+type SupportsResponse = SupportsPermitResponse;
+
+/// The parameter schema for `nonceOf` function.
+#[derive(Serialize, SchemaType)]
+struct NonceOfParams {
+    #[concordium(size_length = 2)]
+    queries: Vec<AccountAddress>,
+}
+
+/// The response schema for `nonceOf` function.
+#[derive(Debug, PartialEq, Eq, Serialize, SchemaType)]
+struct NonceOfResponse {
+    #[concordium(size_length = 2)]
+    nonces: Vec<u64>,
+}
+
+/// The response schema for `view_admin` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewAdminRes {
+    admin: AccountAddress,
+    staking_contract_addr: ContractAddress,
+    user_contract_addr: ContractAddress,
+}
+
+/// The response schema for `view_project` function.
+type ViewProjectResponse = ProjectState;
+
+/// The response schema for `view_projects` function.
+type ViewProjectsResponse = Vec<(ProjectId, ProjectState)>;
+
+/// The response schema for `view_project_metadata_history` function.
+type ViewProjectMetadataHistoryResponse = Vec<MetadataRecord>;
+
+/// The response schema for `view_tags` function.
+type ViewTagsResponse = Vec<Tag>;
+
+/// The parameter schema for `view_rewards` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewRewardsParams {
+    account: AccountAddress,
+}
+
+/// The response schema for `view_rewards` function.
+type ViewRewardsResponse = RewardState;
+
+/// The response schema for `claim_rewards` function.
+/// Points claimed by this call.
+type ClaimRewardsResponse = u64;
+
+/// The parameter schema for `view_curator_stats` function.
+#[derive(Serial, Deserial, SchemaType)]
+struct ViewStatsParams {
+    account: AccountAddress,
 }
 
-/// Init project and add to project map.
+/// The response schema for `view_curator_stats` and `view_validator_stats` function.
+type ViewStatsResponse = ProjectStats;
+
+/// The parameter schema for `view_curation_quota` function.
+type ViewCurationQuotaParams = ViewStatsParams;
+
+/// The response schema for `view_curation_quota` function.
+#[derive(Debug, PartialEq, Eq, Serial, Deserial, SchemaType)]
+struct ViewCurationQuotaResponse {
+    /// Number of Candidate projects the curator can still curate.
+    remaining_open_candidates: u32,
+    /// Number of projects the curator can still curate in the current window.
+    remaining_curations: u32,
+    /// Start of the current window, or None if the curator has not curated in it.
+    window_start: Option<Timestamp>,
+}
+
+/// The response schema for `view_listeners` function.
+type ViewListenersResponse = Vec<Listener>;
+
+/// The response schema for `view_projects_by_tag` function.
+type ViewProjectsByTagResponse = Vec<ProjectId>;
+
+/// The response schema for `view_pub_keys` function.
+type ViewPubKeysResponse = Vec<PubKeyRecord>;
+
+/// The response schema for `view_project_ids` function.
+type ViewProjectIdsResponse = Vec<ProjectId>;
+
+/// The response schema for `view_unlocked_percentage` function.
+/// Unlocked percentage in basis points (10000 = 100%).
+type ViewUnlockedPercentageResponse = u16;
+
+/// Custom error definitions of OVERLAY projects smart contract.
+#[derive(Debug, PartialEq, Eq, Reject, Serialize, SchemaType)]
+#[allow(clippy::enum_variant_names)]
+enum Error {
+    #[from(ParseError)]
+    ParseParamsError,
+    InvalidCaller,
+    InvalidStatus,
+    ShouldNotBeTON,
+    OnlyAccount,
+    FailedInvokeUserContract,
+    FailedInvokeUserContractView,
+    ProjectHasBeenInitializedAlready,
+    ProjectNotFound,
+    InvalidVestingSchedule,
+    VestingScheduleNotProposed,
+    VestingScheduleNotFound,
+    SaleNotFound,
+    FailedInvokeSaleContract,
+    AlreadyRegistered,
+    WhitelistFull,
+    RegistrationClosed,
+    RegistrationNotClosed,
+    LotteryAlreadyCommitted,
+    LotteryNotCommitted,
+    LotteryAlreadyRevealed,
+    InvalidLotterySeed,
+    InvalidAllocationTiers,
+    FailedInvokeStakingContractView,
+    PublicKeyNotFound,
+    WrongContract,
+    InvalidNonce,
+    InvalidSignature,
+    PublicKeyAlreadyExists,
+    #[from(LogError)]
+    LogFailed,
+    Expired,
+    UnsupportedEntrypoint,
+    ProjectUriNotProposed,
+    MissingMetadataHash,
+    InvalidProjectProfile,
+    ProjectProfileNotProposed,
+    InvalidTag,
+    TagAlreadyExists,
+    TagNotFound,
+    InvalidProjectId,
+    EmptyOwners,
+    DuplicateOwners,
+    TooManyOwners,
+    InvalidMaxOwners,
+    InvalidOwnerThreshold,
+    OwnerProposalNotFound,
+    AlreadyApproved,
+    TokenAddrConflict,
+    MergeIntoSelf,
+    ListenerAlreadyExists,
+    ListenerNotFound,
+    TooManyListeners,
+    FailedInvokeListener,
+    NoPendingRewards,
+    CurationLimitExceeded,
+    InvalidWhitelistCap,
+    InvalidWinnerCount,
+    ValidatorIsCurator,
+}
+
+type ContractResult<A> = Result<A, Error>;
+
+/// The smart contract module init function.
+/// Although anyone can init this module, this function is expected to be called by OVERLAY team.
+#[init(contract = "overlay-projects", parameter = "InitParams")]
+fn contract_init<S: HasStateApi>(
+    ctx: &impl HasInitContext,
+    state_builder: &mut StateBuilder<S>,
+) -> InitResult<State<S>> {
+    let params: InitParams = ctx.parameter_cursor().get()?;
+    let state = State {
+        admin: ctx.init_origin(),
+        staking_contract_addr: params.staking_contract_addr,
+        user_contract_addr: params.user_contract_addr,
+        project: state_builder.new_map(),
+        whitelist: state_builder.new_map(),
+        nonces: state_builder.new_map(),
+        metadata_history: state_builder.new_map(),
+        tags: state_builder.new_map(),
+        project_id_counter: 0,
+        max_owners: DEFAULT_MAX_OWNERS,
+        archive: state_builder.new_map(),
+        listeners: Vec::new(),
+        rewards: state_builder.new_map(),
+        reward_points: RewardPoints {
+            whitelist: DEFAULT_WHITELIST_REWARD_POINTS,
+            sale_closed: DEFAULT_SALE_CLOSED_REWARD_POINTS,
+        },
+        curator_stats: state_builder.new_map(),
+        validator_stats: state_builder.new_map(),
+        curation_limits: CurationLimits {
+            max_open_candidates: DEFAULT_MAX_OPEN_CANDIDATES,
+            max_curations_per_window: DEFAULT_MAX_CURATIONS_PER_WINDOW,
+            window: Duration::from_millis(DEFAULT_CURATION_WINDOW_MILLIS),
+        },
+        curator_quotas: state_builder.new_map(),
+    };
+    Ok(state)
+}
+
+/// Update associated staking/user contract address.
 ///
 /// Caller: current admin account.
 /// Reject if:
 /// * Caller is not the current admin account.
-/// * The inputted project has already registered.
 #[receive(
     contract = "overlay-projects",
-    name = "apply_curate_project",
-    parameter = "ApplyCurateProjectParams",
+    name = "update_contract_state",
+    parameter = "UpdateContractStateParams",
     mutable,
     error = "Error"
 )]
-fn contract_apply_curate_project<S: HasStateApi>(
+fn contract_update_contract_state<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
     host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
     let state = host.state_mut();
     ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    let params: ApplyCurateProjectParams = ctx.parameter_cursor().get()?;
-    let existed = state.project.insert(
-        params.project_id,
-        ProjectState {
-            project_uri: Some(params.project_uri),
-            owners: params.owners,
-            pub_key: None,
-            token_addr: None,
-            seed_nft_addr: None,
-            sale_addr: None,
-            status: ProjectStatus::Candidate,
-        },
-    );
-    ensure!(existed.is_none(), Error::ProjectHasBeenInitializedAlready);
+    let params: UpdateContractStateParams = ctx.parameter_cursor().get()?;
+    state.staking_contract_addr = params.staking_contract_addr;
+    state.user_contract_addr = params.user_contract_addr;
     Ok(())
 }
 
-/// Add inputted project to curated project list of caller's overlay-user state.
-/// If the project has not been registered, a new project is registered.
+/// Update max number of owners of a project.
+/// Projects which already have more owners than the new max keep their owners.
 ///
-/// Caller: Anyone who is a curator user.
+/// Caller: current admin account.
 /// Reject if:
-/// * Caller is not overlay user marked as curator.
+/// * Caller is not the current admin account.
+/// * The inputted max number of owners is zero.
 #[receive(
     contract = "overlay-projects",
-    name = "curate_project",
-    parameter = "CurateProjectParams",
+    name = "set_max_owners",
+    parameter = "SetMaxOwnersParams",
     mutable,
     error = "Error"
 )]
-fn contract_curate_project<S: HasStateApi>(
+fn contract_set_max_owners<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
     host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
-    let params: CurateProjectParams = ctx.parameter_cursor().get()?;
-
-    // let's check the caller is the curator.
-    let func = EntrypointName::new_unchecked("view_user");
-    let user_contract_addr = host.state_mut().user_contract_addr;
-    let sender_account = match ctx.sender() {
-        Address::Contract(_) => bail!(Error::OnlyAccount),
-        Address::Account(account_address) => account_address,
-    };
-    let view_user_params = ViewUserParams {
-        addr: sender_account,
-    };
-    let user_state: UserStateResponse = host
-        .invoke_contract_read_only(&user_contract_addr, &view_user_params, func, Amount::zero())
-        .unwrap()
-        .ok_or(Error::FailedInvokeUserContractView)?
-        .get()?;
-    ensure!(user_state.is_curator, Error::InvalidCaller);
-
     let state = host.state_mut();
-    state
-        .project
-        .entry(params.project_id.clone())
-        .or_insert_with(|| ProjectState {
-            project_uri: Some(params.project_uri),
-            owners: params.owners,
-            pub_key: None,
-            token_addr: None,
-            seed_nft_addr: None,
-            sale_addr: None,
-            status: ProjectStatus::Candidate,
-        });
-
-    // let's add the project to curated project list of this overlay-user's state.
-    let func = EntrypointName::new("curate".into()).unwrap();
-    let curate_param = CurateParams {
-        addr: sender_account,
-        project_id: params.project_id,
-    };
-    host.invoke_contract(&user_contract_addr, &curate_param, func, Amount::zero())
-        .map(|(_, _)| ())
-        .map_err(|_| Error::FailedInvokeUserContract)
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let params: SetMaxOwnersParams = ctx.parameter_cursor().get()?;
+    ensure!(params.max_owners > 0, Error::InvalidMaxOwners);
+    state.max_owners = params.max_owners;
+    Ok(())
 }
 
-/// This function is called by OVERLAY admin to curate a new project on behalf of curators.
+/// Update reward points accrued to the curator and the validator of a project reaching Whitelist
+/// and TokenGenerated, when its closed sale can no longer be refunded.
 ///
 /// Caller: current admin account.
 /// Reject if:
 /// * Caller is not the current admin account.
-/// * The inputted curator account address is not a curator.
 #[receive(
     contract = "overlay-projects",
-    name = "curate_project_admin",
-    parameter = "CurateProjectAdminParams",
+    name = "set_reward_points",
+    parameter = "SetRewardPointsParams",
     mutable,
     error = "Error"
 )]
-fn contract_curate_project_admin<S: HasStateApi>(
+fn contract_set_reward_points<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
     host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
-    ensure!(ctx.invoker() == host.state().admin, Error::InvalidCaller);
-    let params: CurateProjectAdminParams = ctx.parameter_cursor().get()?;
-    let func = EntrypointName::new_unchecked("view_user");
-    let user_contract_addr = host.state_mut().user_contract_addr;
-    let view_user_params = ViewUserParams {
-        addr: params.curator,
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let params: SetRewardPointsParams = ctx.parameter_cursor().get()?;
+    state.reward_points = params;
+    Ok(())
+}
+
+/// Claim all pending reward points of the caller, returning the claimed points and logging a
+/// `RewardsClaimed` event so that off-chain reward distribution can follow the claims.
+///
+/// Caller: Any accounts
+/// Reject if:
+/// * Caller is not an account.
+/// * Caller has no pending reward points.
+/// * Fails to log the event.
+#[receive(
+    contract = "overlay-projects",
+    name = "claim_rewards",
+    return_value = "ClaimRewardsResponse",
+    mutable,
+    error = "Error",
+    enable_logger
+)]
+fn contract_claim_rewards<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+) -> ContractResult<ClaimRewardsResponse> {
+    let sender_account = match ctx.sender() {
+        Address::Contract(_) => bail!(Error::OnlyAccount),
+        Address::Account(account_address) => account_address,
+    };
+    let reward = host.state_mut().rewards.get_mut(&sender_account);
+    ensure!(
+        reward.as_ref().map_or(false, |reward| reward.pending > 0),
+        Error::NoPendingRewards
+    );
+    let mut reward = reward.unwrap();
+    let points = reward.pending;
+    reward.pending = 0;
+    reward.claimed = reward.claimed.saturating_add(points);
+    logger.log(&RewardsClaimedEvent {
+        account: sender_account,
+        points,
+    })?;
+    Ok(points)
+}
+
+/// Update limits of the projects curated by a curator through `curate_project`.
+/// Curators who already exceed the new limits can not curate until they are back under them.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-projects",
+    name = "set_curation_limits",
+    parameter = "SetCurationLimitsParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_set_curation_limits<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let params: SetCurationLimitsParams = ctx.parameter_cursor().get()?;
+    state.curation_limits = params;
+    Ok(())
+}
+
+/// Transfer admin of this module to another account.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+#[receive(
+    contract = "overlay-projects",
+    name = "transfer_admin",
+    parameter = "TransferAdminParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_transfer_admin<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let params: TransferAdminParams = ctx.parameter_cursor().get()?;
+    state.admin = params.admin;
+    Ok(())
+}
+
+/// Init project and add to project map, returning the id of the project.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id is not valid.
+/// * The inputted owners are empty, have duplicates or exceed the max number of owners.
+/// * The inputted project has already registered.
+/// * Invoking `update_project_status` of the overlay-users contract fails.
+#[receive(
+    contract = "overlay-projects",
+    name = "apply_curate_project",
+    parameter = "ApplyCurateProjectParams",
+    return_value = "ProjectId",
+    mutable,
+    error = "Error"
+)]
+fn contract_apply_curate_project<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ProjectId> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let params: ApplyCurateProjectParams = ctx.parameter_cursor().get()?;
+    validate_owners(state, &params.owners)?;
+    let project_id = resolve_project_id(state, params.project_id)?;
+    let owner_threshold = majority_threshold(&params.owners);
+    let existed = state.project.insert(
+        project_id.clone(),
+        ProjectState {
+            project_uri: Some(params.project_uri.clone()),
+            owners: params.owners,
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Candidate,
+            token_generated_at: None,
+            listing_addr: None,
+            listed_at: None,
+            vesting_addr: None,
+            vesting_started_at: None,
+            vesting_schedule: None,
+            pending_vesting_schedule: None,
+            allocation_tiers: Vec::new(),
+            signed_action_nonce: 0,
+            pub_keys: Vec::new(),
+            pending_project_uri: None,
+            profile: ProjectProfile::default(),
+            pending_profile: None,
+            tags: Vec::new(),
+            owner_threshold,
+            owner_proposal: None,
+            merged_into: None,
+            curated_by: None,
+            validated_by: None,
+        },
+    );
+    ensure!(existed.is_none(), Error::ProjectHasBeenInitializedAlready);
+    record_metadata(
+        state,
+        &project_id,
+        MetadataRecord {
+            uri: params.project_uri,
+            timestamp: ctx.metadata().slot_time(),
+            changed_by: ctx.invoker(),
+        },
+    );
+    notify_project_created(host, project_id.clone())?;
+    Ok(project_id)
+}
+
+/// Compute the quota of the inputted curator left at the inputted time.
+fn remaining_curation_quota<S: HasStateApi>(
+    state: &State<S>,
+    curator: AccountAddress,
+    now: Timestamp,
+) -> ViewCurationQuotaResponse {
+    let limits = &state.curation_limits;
+    let (open_candidates, window_curations, window_start) = match state.curator_quotas.get(&curator)
+    {
+        Some(quota) if !is_window_expired(quota.window_start, now, limits.window) => (
+            quota.open_candidates,
+            quota.window_curations,
+            Some(quota.window_start),
+        ),
+        Some(quota) => (quota.open_candidates, 0, None),
+        None => (0, 0, None),
+    };
+    ViewCurationQuotaResponse {
+        remaining_open_candidates: limits.max_open_candidates.saturating_sub(open_candidates),
+        remaining_curations: limits
+            .max_curations_per_window
+            .saturating_sub(window_curations),
+        window_start,
+    }
+}
+
+/// Count a new Candidate project curated by the inputted curator at the inputted time, starting a
+/// new window if the current one has expired.
+fn record_curation<S: HasStateApi>(state: &mut State<S>, curator: AccountAddress, now: Timestamp) {
+    let window = state.curation_limits.window;
+    let mut quota = state
+        .curator_quotas
+        .entry(curator)
+        .or_insert_with(|| CuratorQuota {
+            open_candidates: 0,
+            window_start: now,
+            window_curations: 0,
+        });
+    if is_window_expired(quota.window_start, now, window) {
+        quota.window_start = now;
+        quota.window_curations = 0;
+    }
+    quota.open_candidates += 1;
+    quota.window_curations += 1;
+}
+
+/// Release a Candidate slot of the inputted curator when its project leaves Candidate.
+fn release_candidate<S: HasStateApi>(state: &mut State<S>, curator: Option<AccountAddress>) {
+    if let Some(curator) = curator {
+        if let Some(mut quota) = state.curator_quotas.get_mut(&curator) {
+            quota.open_candidates = quota.open_candidates.saturating_sub(1);
+        }
+    }
+}
+
+/// Check whether the window started at the inputted time has expired at the inputted time.
+fn is_window_expired(window_start: Timestamp, now: Timestamp, window: Duration) -> bool {
+    now.duration_since(window_start)
+        .map_or(false, |elapsed| elapsed >= window)
+}
+
+/// Normalize the inputted project id by trimming whitespaces and lowercasing, and check that it
+/// follows the project id grammar: one or more groups of lowercase ASCII letters and digits
+/// separated by single hyphens, at most `MAX_PROJECT_ID_LENGTH` long.
+/// Every entrypoint normalizes the inputted project ids, so that a project can be referred to by
+/// any id which normalizes to its id.
+fn normalize_project_id(project_id: &str) -> ContractResult<ProjectId> {
+    let project_id = project_id.trim().to_ascii_lowercase();
+    ensure!(
+        project_id.len() <= MAX_PROJECT_ID_LENGTH
+            && project_id.split('-').all(|group| {
+                !group.is_empty()
+                    && group
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            }),
+        Error::InvalidProjectId
+    );
+    Ok(project_id)
+}
+
+/// Normalize the inputted project id of a new project. Ids of archived projects can not be reused.
+/// Generate a new sequential project id if None is inputted.
+fn resolve_project_id<S: HasStateApi>(
+    state: &mut State<S>,
+    project_id: Option<ProjectId>,
+) -> ContractResult<ProjectId> {
+    match project_id {
+        Some(project_id) => {
+            let project_id = normalize_project_id(&project_id)?;
+            ensure!(
+                state.archive.get(&project_id).is_none(),
+                Error::ProjectHasBeenInitializedAlready
+            );
+            Ok(project_id)
+        },
+        None => loop {
+            state.project_id_counter += 1;
+            let project_id = format!(
+                "{}{}",
+                GENERATED_PROJECT_ID_PREFIX, state.project_id_counter
+            );
+            if state.project.get(&project_id).is_none() && state.archive.get(&project_id).is_none()
+            {
+                return Ok(project_id);
+            }
+        },
+    }
+}
+
+/// Check that the inputted owners are not empty, have no duplicates and do not exceed the max
+/// number of owners.
+fn validate_owners<S: HasStateApi>(
+    state: &State<S>,
+    owners: &[AccountAddress],
+) -> ContractResult<()> {
+    ensure!(!owners.is_empty(), Error::EmptyOwners);
+    ensure!(
+        owners.len() <= state.max_owners as usize,
+        Error::TooManyOwners
+    );
+    for (i, owner) in owners.iter().enumerate() {
+        ensure!(!owners[..i].contains(owner), Error::DuplicateOwners);
+    }
+    Ok(())
+}
+
+/// Append the inputted record to the metadata history of the inputted project.
+fn record_metadata<S: HasStateApi>(
+    state: &mut State<S>,
+    project_id: &ProjectId,
+    record: MetadataRecord,
+) {
+    let mut history = state
+        .metadata_history
+        .get(project_id)
+        .map(|history| history.clone())
+        .unwrap_or_default();
+    history.push(record);
+    state.metadata_history.insert(project_id.clone(), history);
+}
+
+/// Add inputted project to curated project list of caller's overlay-user state, returning the id
+/// of the project.
+/// If the project has not been registered, a new project is registered.
+///
+/// Caller: Anyone who is a curator user.
+/// Reject if:
+/// * Caller is not overlay user marked as curator.
+/// * The inputted project id is not valid.
+/// * The inputted owners are empty, have duplicates or exceed the max number of owners.
+/// * The project is new and the caller has reached the max number of open Candidate projects or
+///   of curations in the current window.
+/// * Invoking `curate` or `update_project_status` of the overlay-users contract fails.
+#[receive(
+    contract = "overlay-projects",
+    name = "curate_project",
+    parameter = "CurateProjectParams",
+    return_value = "ProjectId",
+    mutable,
+    error = "Error"
+)]
+fn contract_curate_project<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ProjectId> {
+    let params: CurateProjectParams = ctx.parameter_cursor().get()?;
+
+    // let's check the caller is the curator.
+    let func = EntrypointName::new_unchecked("view_user");
+    let user_contract_addr = host.state_mut().user_contract_addr;
+    let sender_account = match ctx.sender() {
+        Address::Contract(_) => bail!(Error::OnlyAccount),
+        Address::Account(account_address) => account_address,
+    };
+    let view_user_params = ViewUserParams {
+        addr: sender_account,
+    };
+    let user_state: UserStateResponse = host
+        .invoke_contract_read_only(&user_contract_addr, &view_user_params, func, Amount::zero())
+        .unwrap()
+        .ok_or(Error::FailedInvokeUserContractView)?
+        .get()?;
+    ensure!(user_state.is_curator, Error::InvalidCaller);
+
+    let state = host.state_mut();
+    validate_owners(state, &params.owners)?;
+    let project_id = resolve_project_id(state, params.project_id)?;
+    let is_new = state.project.get(&project_id).is_none();
+    if is_new {
+        let now = ctx.metadata().slot_time();
+        let quota = remaining_curation_quota(state, sender_account, now);
+        ensure!(
+            quota.remaining_open_candidates > 0 && quota.remaining_curations > 0,
+            Error::CurationLimitExceeded
+        );
+        record_curation(state, sender_account, now);
+        record_metadata(
+            state,
+            &project_id,
+            MetadataRecord {
+                uri: params.project_uri.clone(),
+                timestamp: now,
+                changed_by: sender_account,
+            },
+        );
+        update_account_stats(&mut state.curator_stats, sender_account, |stats| {
+            stats.projects += 1
+        });
+    }
+    let owner_threshold = majority_threshold(&params.owners);
+    state
+        .project
+        .entry(project_id.clone())
+        .or_insert_with(|| ProjectState {
+            project_uri: Some(params.project_uri),
+            owners: params.owners,
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Candidate,
+            token_generated_at: None,
+            listing_addr: None,
+            listed_at: None,
+            vesting_addr: None,
+            vesting_started_at: None,
+            vesting_schedule: None,
+            pending_vesting_schedule: None,
+            allocation_tiers: Vec::new(),
+            signed_action_nonce: 0,
+            pub_keys: Vec::new(),
+            pending_project_uri: None,
+            profile: ProjectProfile::default(),
+            pending_profile: None,
+            tags: Vec::new(),
+            owner_threshold,
+            owner_proposal: None,
+            merged_into: None,
+            curated_by: Some(sender_account),
+            validated_by: None,
+        });
+
+    // let's add the project to curated project list of this overlay-user's state.
+    let func = EntrypointName::new("curate").unwrap();
+    let curate_param = CurateParams {
+        addr: sender_account,
+        project_id: project_id.clone(),
+    };
+    host.invoke_contract(&user_contract_addr, &curate_param, func, Amount::zero())
+        .map_err(|_| Error::FailedInvokeUserContract)?;
+    if is_new {
+        notify_project_created(host, project_id.clone())?;
+    }
+    Ok(project_id)
+}
+
+/// This function is called by OVERLAY admin to curate a new project on behalf of curators,
+/// returning the id of the project. The project is added to curated project list of the inputted
+/// curator's overlay-user state.
+/// A new project counts towards the curation quota of the curator, but the limits are not applied.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted curator account address is not a curator.
+/// * The inputted project id is not valid.
+/// * The inputted owners are empty, have duplicates or exceed the max number of owners.
+/// * Invoking `curate` or `update_project_status` of the overlay-users contract fails.
+#[receive(
+    contract = "overlay-projects",
+    name = "curate_project_admin",
+    parameter = "CurateProjectAdminParams",
+    return_value = "ProjectId",
+    mutable,
+    error = "Error"
+)]
+fn contract_curate_project_admin<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ProjectId> {
+    ensure!(ctx.invoker() == host.state().admin, Error::InvalidCaller);
+    let params: CurateProjectAdminParams = ctx.parameter_cursor().get()?;
+    let func = EntrypointName::new_unchecked("view_user");
+    let user_contract_addr = host.state_mut().user_contract_addr;
+    let view_user_params = ViewUserParams {
+        addr: params.curator,
     };
     let user_state: UserStateResponse = host
         .invoke_contract_read_only(&user_contract_addr, &view_user_params, func, Amount::zero())
@@ -420,9 +1783,28 @@ fn contract_curate_project_admin<S: HasStateApi>(
 
     ensure!(user_state.is_curator, Error::InvalidCaller);
     let state = host.state_mut();
+    validate_owners(state, &params.owners)?;
+    let project_id = resolve_project_id(state, params.project_id)?;
+    let is_new = state.project.get(&project_id).is_none();
+    if is_new {
+        record_curation(state, params.curator, ctx.metadata().slot_time());
+        record_metadata(
+            state,
+            &project_id,
+            MetadataRecord {
+                uri: params.project_uri.clone(),
+                timestamp: ctx.metadata().slot_time(),
+                changed_by: ctx.invoker(),
+            },
+        );
+        update_account_stats(&mut state.curator_stats, params.curator, |stats| {
+            stats.projects += 1
+        });
+    }
+    let owner_threshold = majority_threshold(&params.owners);
     state
         .project
-        .entry(params.project_id.clone())
+        .entry(project_id.clone())
         .or_insert_with(|| ProjectState {
             project_uri: Some(params.project_uri),
             owners: params.owners,
@@ -431,16 +1813,54 @@ fn contract_curate_project_admin<S: HasStateApi>(
             seed_nft_addr: None,
             sale_addr: None,
             status: ProjectStatus::Candidate,
+            token_generated_at: None,
+            listing_addr: None,
+            listed_at: None,
+            vesting_addr: None,
+            vesting_started_at: None,
+            vesting_schedule: None,
+            pending_vesting_schedule: None,
+            allocation_tiers: Vec::new(),
+            signed_action_nonce: 0,
+            pub_keys: Vec::new(),
+            pending_project_uri: None,
+            profile: ProjectProfile::default(),
+            pending_profile: None,
+            tags: Vec::new(),
+            owner_threshold,
+            owner_proposal: None,
+            merged_into: None,
+            curated_by: Some(params.curator),
+            validated_by: None,
         });
-    Ok(())
+
+    // let's add the project to curated project list of the curator's overlay-user state.
+    let func = EntrypointName::new_unchecked("curate");
+    let curate_param = CurateParams {
+        addr: params.curator,
+        project_id: project_id.clone(),
+    };
+    host.invoke_contract(&user_contract_addr, &curate_param, func, Amount::zero())
+        .map_err(|_| Error::FailedInvokeUserContract)?;
+    if is_new {
+        notify_project_created(host, project_id.clone())?;
+    }
+    Ok(project_id)
 }
 
 /// Add inputted project to validated project list of caller's overlay-user state.
+/// The inputted owners and token address replace the current ones unless they are empty or None.
 ///
 /// Caller: Anyone who is a validator user.
 /// Reject if:
 /// * Caller is not overlay user marked as validator.
+/// * The inputted owners have duplicates or exceed the max number of owners.
 /// * The inputted project id has not been registered or its status is not Candidate.
+/// * The project uri of the inputted project does not have its metadata hash.
+/// * Caller is the curator of the inputted project.
+/// * The token address is inputted and the inputted project has seed nft address.
+/// * The token address is inputted and the inputted project has a different token address.
+/// * Invoking `update_project_status` of the overlay-users contract fails.
 #[receive(
     contract = "overlay-projects",
     name = "validate_project",
@@ -452,10 +1872,14 @@ fn contract_validate_project<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
     host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
-    let params: ValidateProjectParams = ctx.parameter_cursor().get()?;
+    let mut params: ValidateProjectParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    if !params.owners.is_empty() {
+        validate_owners(host.state(), &params.owners)?;
+    }
 
     // let's check the caller is the curator.
-    let func = EntrypointName::new("view_user".into()).unwrap();
+    let func = EntrypointName::new("view_user").unwrap();
     let user_contract_addr = host.state_mut().user_contract_addr;
     let sender_account = match ctx.sender() {
         Address::Contract(_) => bail!(Error::OnlyAccount),
@@ -472,22 +1896,38 @@ fn contract_validate_project<S: HasStateApi>(
     ensure!(user_state.is_validator, Error::InvalidCaller);
 
     let state = host.state_mut();
-    let project = state.project.get(&params.project_id);
+    let project = state.project.get_mut(&params.project_id);
     ensure!(project.is_some(), Error::ProjectNotFound);
-    let project = project.unwrap();
+    let mut project = project.unwrap();
     ensure!(
         project.status == ProjectStatus::Candidate,
         Error::InvalidStatus
     );
-
-    state
-        .project
-        .entry(params.project_id.clone())
-        .and_modify(|project_state| {
-            project_state.status = ProjectStatus::Whitelist;
-        });
-
-    let func = EntrypointName::new("validate".into()).unwrap();
+    ensure!(
+        project
+            .project_uri
+            .as_ref()
+            .map_or(false, |uri| uri.hash.is_some()),
+        Error::MissingMetadataHash
+    );
+    finalize_validation(
+        &mut project,
+        sender_account,
+        params.owners,
+        params.token_addr,
+    )?;
+    let curated_by = project.curated_by;
+    drop(project);
+    release_candidate(state, curated_by);
+    let points = state.reward_points.whitelist;
+    accrue_rewards(state, &params.project_id, points);
+    update_account_stats(&mut state.validator_stats, sender_account, |stats| {
+        stats.projects += 1
+    });
+    update_project_stats(state, &params.project_id, |stats| stats.whitelisted += 1);
+    notify_status_change(host, params.project_id.clone(), ProjectStatus::Whitelist)?;
+
+    let func = EntrypointName::new("validate").unwrap();
     let validate_param = ValidateParams {
         addr: sender_account,
         project_id: params.project_id,
@@ -498,11 +1938,19 @@ fn contract_validate_project<S: HasStateApi>(
 }
 
 /// This function is called by OVERLAY admin to validate a project on behalf of validators.
+/// The project is added to validated project list of the inputted validator's overlay-user state.
+/// The inputted owners and token address replace the current ones unless they are empty or None.
 ///
 /// Caller: current admin account.
 /// Reject if:
 /// * Caller is not the current admin account.
 /// * The inputted curator account address is not a validator.
+/// * The inputted owners have duplicates or exceed the max number of owners.
+/// * The project uri of the inputted project does not have its metadata hash.
+/// * The inputted validator is the curator of the inputted project.
+/// * The token address is inputted and the inputted project has seed nft address.
+/// * The token address is inputted and the inputted project has a different token address.
+/// * Invoking `update_project_status` or `validate` of the overlay-users contract fails.
 #[receive(
     contract = "overlay-projects",
     name = "validate_project_admin",
@@ -515,10 +1963,14 @@ fn contract_validate_project_admin<S: HasStateApi>(
     host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
     ensure!(ctx.invoker() == host.state().admin, Error::InvalidCaller);
-    let params: ValidateProjectAdminParams = ctx.parameter_cursor().get()?;
+    let mut params: ValidateProjectAdminParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    if !params.owners.is_empty() {
+        validate_owners(host.state(), &params.owners)?;
+    }
 
     // let's call the inputted validator address is actually a validator.
-    let func = EntrypointName::new("view_user".into()).unwrap();
+    let func = EntrypointName::new("view_user").unwrap();
     let user_contract_addr = host.state_mut().user_contract_addr;
     let view_user_params = ViewUserParams {
         addr: params.validator,
@@ -531,27 +1983,211 @@ fn contract_validate_project_admin<S: HasStateApi>(
     ensure!(user_state.is_validator, Error::InvalidCaller);
 
     let state = host.state_mut();
-    let project = state.project.get(&params.project_id);
+    let project = state.project.get_mut(&params.project_id);
     ensure!(project.is_some(), Error::ProjectNotFound);
-    let project = project.unwrap();
+    let mut project = project.unwrap();
     ensure!(
         project.status == ProjectStatus::Candidate,
         Error::InvalidStatus
     );
-    state
-        .project
-        .entry(params.project_id.clone())
-        .and_modify(|project_state| {
-            project_state.status = ProjectStatus::Whitelist;
-        });
-    Ok(())
+    ensure!(
+        project
+            .project_uri
+            .as_ref()
+            .map_or(false, |uri| uri.hash.is_some()),
+        Error::MissingMetadataHash
+    );
+    finalize_validation(
+        &mut project,
+        params.validator,
+        params.owners,
+        params.token_addr,
+    )?;
+    let curated_by = project.curated_by;
+    drop(project);
+    release_candidate(state, curated_by);
+    let points = state.reward_points.whitelist;
+    accrue_rewards(state, &params.project_id, points);
+    update_account_stats(&mut state.validator_stats, params.validator, |stats| {
+        stats.projects += 1
+    });
+    update_project_stats(state, &params.project_id, |stats| stats.whitelisted += 1);
+    notify_status_change(host, params.project_id.clone(), ProjectStatus::Whitelist)?;
+
+    // let's add the project to validated project list of the validator's overlay-user state.
+    let func = EntrypointName::new_unchecked("validate");
+    let validate_param = ValidateParams {
+        addr: params.validator,
+        project_id: params.project_id,
+    };
+    host.invoke_contract(&user_contract_addr, &validate_param, func, Amount::zero())
+        .map(|(_, _)| ())
+        .map_err(|_| Error::FailedInvokeUserContract)
 }
 
-/// Update token address of the inputted project.
-///
-/// Caller: Owner of the project.
-/// Reject if:
-/// * Caller is not the owner of the project.
+/// Move the inputted project to Whitelist validated by the inputted validator, finalizing its
+/// owners and token address.
+/// Empty owners and None token address keep the current ones.
+/// The curator of the project can not validate it, so that one account can not earn both the
+/// curator and the validator rewards and stats of a project.
+fn finalize_validation(
+    project: &mut ProjectState,
+    validator: AccountAddress,
+    owners: Vec<AccountAddress>,
+    token_addr: Option<ContractAddress>,
+) -> ContractResult<()> {
+    ensure!(
+        project.curated_by != Some(validator),
+        Error::ValidatorIsCurator
+    );
+    if let Some(token_addr) = token_addr {
+        ensure!(project.seed_nft_addr.is_none(), Error::InvalidStatus);
+        ensure!(
+            project
+                .token_addr
+                .map_or(true, |current| current == token_addr),
+            Error::TokenAddrConflict
+        );
+        project.token_addr = Some(token_addr);
+    }
+    if !owners.is_empty() {
+        project.replace_owners(owners);
+    }
+    project.status = ProjectStatus::Whitelist;
+    project.validated_by = Some(validator);
+    Ok(())
+}
+
+/// Add the inputted reward points to the pending rewards of the curator and the validator of the
+/// inputted project.
+fn accrue_rewards<S: HasStateApi>(state: &mut State<S>, project_id: &ProjectId, points: u64) {
+    let accounts = match state.project.get(project_id) {
+        Some(project) => [project.curated_by, project.validated_by],
+        None => return,
+    };
+    for account in accounts.iter().flatten() {
+        let mut reward = state.rewards.entry(*account).or_default();
+        reward.pending = reward.pending.saturating_add(points);
+    }
+}
+
+/// Apply the inputted update to the stats of the curator and the validator of the inputted
+/// project.
+fn update_project_stats<S: HasStateApi>(
+    state: &mut State<S>,
+    project_id: &ProjectId,
+    update: impl Fn(&mut ProjectStats),
+) {
+    let (curator, validator) = match state.project.get(project_id) {
+        Some(project) => (project.curated_by, project.validated_by),
+        None => return,
+    };
+    if let Some(curator) = curator {
+        update_account_stats(&mut state.curator_stats, curator, &update);
+    }
+    if let Some(validator) = validator {
+        update_account_stats(&mut state.validator_stats, validator, &update);
+    }
+}
+
+/// Apply the inputted update to the stats of the inputted account.
+fn update_account_stats<S: HasStateApi>(
+    stats: &mut StateMap<AccountAddress, ProjectStats, S>,
+    account: AccountAddress,
+    update: impl Fn(&mut ProjectStats),
+) {
+    let mut account_stats = stats.entry(account).or_default();
+    update(&mut account_stats);
+}
+
+/// Notify the overlay-users contract and the registered listener contracts of the new status of
+/// the inputted project, so that curated and validated project lists of its users follow the
+/// project status.
+/// Every status change of a project must call this function.
+fn notify_status_change<S: HasStateApi>(
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    project_id: ProjectId,
+    status: ProjectStatus,
+) -> ContractResult<()> {
+    update_user_project_status(host, &project_id, &status)?;
+    notify_listeners(
+        host,
+        ProjectNotification {
+            project_id,
+            status,
+            event: ProjectEvent::StatusChanged,
+        },
+    )
+}
+
+/// Notify the overlay-users contract and the registered listener contracts of the inputted project
+/// registered as Candidate.
+/// Every registration of a project must call this function.
+fn notify_project_created<S: HasStateApi>(
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    project_id: ProjectId,
+) -> ContractResult<()> {
+    update_user_project_status(host, &project_id, &ProjectStatus::Candidate)?;
+    notify_listeners(
+        host,
+        ProjectNotification {
+            project_id,
+            status: ProjectStatus::Candidate,
+            event: ProjectEvent::Created,
+        },
+    )
+}
+
+/// Invoke `update_project_status` of the overlay-users contract with the inputted status of the
+/// inputted project.
+fn update_user_project_status<S: HasStateApi>(
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    project_id: &ProjectId,
+    status: &ProjectStatus,
+) -> ContractResult<()> {
+    let user_contract_addr = host.state().user_contract_addr;
+    let func = EntrypointName::new_unchecked("update_project_status");
+    let update_project_status_params = UpdateProjectStatusParams {
+        project_id: project_id.clone(),
+        status: status.clone(),
+    };
+    host.invoke_contract(
+        &user_contract_addr,
+        &update_project_status_params,
+        func,
+        Amount::zero(),
+    )
+    .map(|(_, _)| ())
+    .map_err(|_| Error::FailedInvokeUserContract)
+}
+
+/// Invoke the registered listener contracts with the inputted notification, rejecting if a
+/// listener with the Revert failure policy fails.
+fn notify_listeners<S: HasStateApi>(
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    notification: ProjectNotification,
+) -> ContractResult<()> {
+    let listeners = host.state().listeners.clone();
+    for listener in listeners.iter() {
+        let result = host.invoke_contract(
+            &listener.address,
+            &notification,
+            listener.entrypoint.as_entrypoint_name(),
+            Amount::zero(),
+        );
+        ensure!(
+            result.is_ok() || listener.failure_policy == ListenerFailurePolicy::Ignore,
+            Error::FailedInvokeListener
+        );
+    }
+    Ok(())
+}
+
+/// Update token address of the inputted project.
+///
+/// Caller: Owner of the project, or anyone with a `permit` message signed by the owner.
+/// Reject if:
+/// * Caller is not the owner of the project.
 /// * The inputted project id has not been registered.
 /// * The inputted project state dose not match with any conditions below.
 ///   * status == Whitelist AND seed_nft_addr != None
@@ -568,508 +2204,10849 @@ fn contract_add_token_addr<S: HasStateApi>(
     host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
     let params: AddTokenAddrParams = ctx.parameter_cursor().get()?;
-    let state = host.state_mut();
+    add_token_addr(host.state_mut(), ctx.invoker(), params)
+}
+
+/// Update token address of the inputted project on behalf of the inputted owner.
+fn add_token_addr<S: HasStateApi>(
+    state: &mut State<S>,
+    owner: AccountAddress,
+    mut params: AddTokenAddrParams,
+) -> ContractResult<()> {
+    params.project_id = normalize_project_id(&params.project_id)?;
     let project = state.project.get_mut(&params.project_id);
     ensure!(project.is_some(), Error::ProjectNotFound);
     let mut project = project.unwrap();
+    ensure!(project.owners.contains(&owner), Error::InvalidCaller);
     ensure!(
-        project.owners.contains(&ctx.invoker()),
-        Error::InvalidCaller
-    );
-    ensure!(
-        (project.status == ProjectStatus::Whitelist && project.seed_nft_addr != None)
-            || (project.status == ProjectStatus::Candidate && project.seed_nft_addr == None),
+        (project.status == ProjectStatus::Whitelist && project.seed_nft_addr.is_some())
+            || (project.status == ProjectStatus::Candidate && project.seed_nft_addr.is_none()),
         Error::InvalidStatus
     );
     project.token_addr = Some(params.token_addr);
     Ok(())
 }
 
-/// Update public key of the inputted project.
+/// Propose a new project URI of the inputted project, which is applied once approved by admin.
+/// The pending project URI is visible in `view_project` until it is approved or rejected.
 ///
-/// Caller: current admin account.
+/// Caller: Owner of the project, or anyone with a `permit` message signed by the owner.
 /// Reject if:
-/// * Caller is not the current admin account.
+/// * Caller is not the owner of the project.
 /// * The inputted project id has not been registered.
-/// * The inputted project state is Candidate
+/// * The inputted project state is not Candidate and the inputted project URI has no hash.
 #[receive(
     contract = "overlay-projects",
-    name = "add_pub_key",
-    parameter = "AddPubKeyParams",
+    name = "propose_project_uri",
+    parameter = "ProposeProjectUriParams",
     mutable,
     error = "Error"
 )]
-fn contract_add_pub_key<S: HasStateApi>(
+fn contract_propose_project_uri<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
     host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
-    let state = host.state_mut();
-    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    let params: AddPubKeyParams = ctx.parameter_cursor().get()?;
+    let params: ProposeProjectUriParams = ctx.parameter_cursor().get()?;
+    propose_project_uri(host.state_mut(), ctx.invoker(), params)
+}
+
+/// Propose a new project URI of the inputted project on behalf of the inputted owner.
+fn propose_project_uri<S: HasStateApi>(
+    state: &mut State<S>,
+    owner: AccountAddress,
+    mut params: ProposeProjectUriParams,
+) -> ContractResult<()> {
+    params.project_id = normalize_project_id(&params.project_id)?;
     let project = state.project.get_mut(&params.project_id);
     ensure!(project.is_some(), Error::ProjectNotFound);
     let mut project = project.unwrap();
-    ensure!(
-        project.status != ProjectStatus::Candidate,
-        Error::InvalidStatus
-    );
-    project.pub_key = Some(params.pub_key);
+    ensure!(project.owners.contains(&owner), Error::InvalidCaller);
+    project.check_project_uri(&params.project_uri)?;
+    project.pending_project_uri = Some(params.project_uri);
     Ok(())
 }
 
-/// Update owners of the inputted project.
+/// Apply the pending project URI of the inputted project.
 ///
 /// Caller: current admin account.
 /// Reject if:
 /// * Caller is not the current admin account.
 /// * The inputted project id has not been registered.
-/// * The inputted project state is Candidate
+/// * No project URI has been proposed for the inputted project.
+/// * The inputted project state is not Candidate and the pending project URI has no hash.
 #[receive(
     contract = "overlay-projects",
-    name = "update_owners",
-    parameter = "UpdateOwnersParams",
+    name = "approve_project_uri",
+    parameter = "ApproveProjectUriParams",
     mutable,
     error = "Error"
 )]
-fn contract_update_owners<S: HasStateApi>(
+fn contract_approve_project_uri<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
     host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
     let state = host.state_mut();
     ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    let params: UpdateOwnersParams = ctx.parameter_cursor().get()?;
+    let mut params: ApproveProjectUriParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
     let project = state.project.get_mut(&params.project_id);
     ensure!(project.is_some(), Error::ProjectNotFound);
     let mut project = project.unwrap();
-    ensure!(
-        project.status != ProjectStatus::Candidate,
-        Error::InvalidStatus
+    let project_uri = project.pending_project_uri.take();
+    ensure!(project_uri.is_some(), Error::ProjectUriNotProposed);
+    project.check_project_uri(project_uri.as_ref().unwrap())?;
+    project.project_uri = project_uri.clone();
+    drop(project);
+    record_metadata(
+        state,
+        &params.project_id,
+        MetadataRecord {
+            uri: project_uri.unwrap(),
+            timestamp: ctx.metadata().slot_time(),
+            changed_by: ctx.invoker(),
+        },
     );
-    project.owners = params.owners;
     Ok(())
 }
 
-/// Update seed NFT address of the inputted project.
+/// Discard the pending project URI of the inputted project.
 ///
 /// Caller: current admin account.
 /// Reject if:
 /// * Caller is not the current admin account.
 /// * The inputted project id has not been registered.
-/// * The inputted project state is not Whitelist
+/// * No project URI has been proposed for the inputted project.
 #[receive(
     contract = "overlay-projects",
-    name = "add_seed_sale",
-    parameter = "AddSeedSaleParams",
+    name = "reject_project_uri",
+    parameter = "RejectProjectUriParams",
     mutable,
     error = "Error"
 )]
-fn contract_add_seed_sale<S: HasStateApi>(
+fn contract_reject_project_uri<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
     host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
     let state = host.state_mut();
     ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    let params: AddSeedSaleParams = ctx.parameter_cursor().get()?;
+    let mut params: RejectProjectUriParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
     let project = state.project.get_mut(&params.project_id);
     ensure!(project.is_some(), Error::ProjectNotFound);
     let mut project = project.unwrap();
     ensure!(
-        project.status == ProjectStatus::Whitelist,
-        Error::InvalidStatus
+        project.pending_project_uri.take().is_some(),
+        Error::ProjectUriNotProposed
     );
-    project.seed_nft_addr = Some(params.seed_nft_addr);
     Ok(())
 }
 
-/// Update sale address of the inputted project.
+/// Propose a new profile of the inputted project, which is applied once confirmed by admin.
 ///
-/// Caller: current admin account.
+/// Caller: Owner of the project, or anyone with a `permit` message signed by the owner.
 /// Reject if:
-/// * Caller is not the current admin account.
+/// * Caller is not the owner of the project.
 /// * The inputted project id has not been registered.
-/// * The inputted project state is not Whitelist.
-/// * The inputted project seed NFT address is not None.
+/// * The inputted profile is not valid.
 #[receive(
     contract = "overlay-projects",
-    name = "add_sale",
-    parameter = "AddSaleParams",
+    name = "propose_project_profile",
+    parameter = "ProposeProjectProfileParams",
     mutable,
     error = "Error"
 )]
-fn contract_add_sale<S: HasStateApi>(
+fn contract_propose_project_profile<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
     host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
-    let state = host.state_mut();
-    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    let params: AddSaleParams = ctx.parameter_cursor().get()?;
+    let params: ProposeProjectProfileParams = ctx.parameter_cursor().get()?;
+    propose_project_profile(host.state_mut(), ctx.invoker(), params)
+}
+
+/// Propose a new profile of the inputted project on behalf of the inputted owner.
+fn propose_project_profile<S: HasStateApi>(
+    state: &mut State<S>,
+    owner: AccountAddress,
+    mut params: ProposeProjectProfileParams,
+) -> ContractResult<()> {
+    params.project_id = normalize_project_id(&params.project_id)?;
     let project = state.project.get_mut(&params.project_id);
     ensure!(project.is_some(), Error::ProjectNotFound);
     let mut project = project.unwrap();
-    ensure!(
-        project.status == ProjectStatus::Whitelist,
-        Error::InvalidStatus
-    );
-    ensure!(project.seed_nft_addr == None, Error::ShouldNotBeTON);
-    project.sale_addr = Some(params.sale_addr);
+    ensure!(project.owners.contains(&owner), Error::InvalidCaller);
+    ensure!(params.profile.is_valid(), Error::InvalidProjectProfile);
+    project.pending_profile = Some(params.profile);
     Ok(())
 }
 
-/// Update the inputted project status as OnSale.
+/// Apply the pending profile of the inputted project.
 ///
 /// Caller: current admin account.
 /// Reject if:
 /// * Caller is not the current admin account.
 /// * The inputted project id has not been registered.
-/// * The inputted project state is not Whitelist.
+/// * No profile has been proposed for the inputted project.
 #[receive(
     contract = "overlay-projects",
-    name = "start_sale",
-    parameter = "StartSaleParams",
-    mutable
+    name = "approve_project_profile",
+    parameter = "ApproveProjectProfileParams",
+    mutable,
+    error = "Error"
 )]
-fn contract_start_sale<S: HasStateApi>(
+fn contract_approve_project_profile<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
     host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
     let state = host.state_mut();
     ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    let params: StartSaleParams = ctx.parameter_cursor().get()?;
+    let mut params: ApproveProjectProfileParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
     let project = state.project.get_mut(&params.project_id);
     ensure!(project.is_some(), Error::ProjectNotFound);
     let mut project = project.unwrap();
-    ensure!(
-        project.status == ProjectStatus::Whitelist,
-        Error::InvalidStatus
-    );
-    project.status = ProjectStatus::OnSale;
+    let profile = project.pending_profile.take();
+    ensure!(profile.is_some(), Error::ProjectProfileNotProposed);
+    project.profile = profile.unwrap();
     Ok(())
 }
 
-/// Update the inputted project status as SaleClosed.
+/// Add a tag which can be assigned to projects.
 ///
 /// Caller: current admin account.
 /// Reject if:
 /// * Caller is not the current admin account.
-/// * The inputted project id has not been registered.
-/// * The inputted project state is not OnSale.
+/// * The inputted tag is empty or longer than `MAX_TAG_LENGTH`.
+/// * The inputted tag has already been added.
 #[receive(
     contract = "overlay-projects",
-    name = "close_sale",
-    parameter = "CloseSaleParams",
-    mutable
+    name = "add_tag",
+    parameter = "AddTagParams",
+    mutable,
+    error = "Error"
 )]
-fn contract_close_sale<S: HasStateApi>(
+fn contract_add_tag<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
     host: &mut impl HasHost<State<S>, StateApiType = S>,
 ) -> ContractResult<()> {
     let state = host.state_mut();
     ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
-    let params: CloseSaleParams = ctx.parameter_cursor().get()?;
-    let project = state.project.get_mut(&params.project_id);
-    ensure!(project.is_some(), Error::ProjectNotFound);
-    let mut project = project.unwrap();
+    let params: AddTagParams = ctx.parameter_cursor().get()?;
     ensure!(
-        project.status == ProjectStatus::OnSale,
-        Error::InvalidStatus
+        !params.tag.is_empty() && params.tag.len() <= MAX_TAG_LENGTH,
+        Error::InvalidTag
     );
-    project.status = ProjectStatus::SaleClosed;
+    let existed = state.tags.insert(params.tag, Vec::new());
+    ensure!(existed.is_none(), Error::TagAlreadyExists);
     Ok(())
 }
 
-/// Smart contract module upgrade function.
-/// For more information see https://developer.concordium.software/en/mainnet/smart-contracts/guides/upgradeable-contract.html#guide-upgradable-contract
+/// Remove the inputted tag, unassigning it from all projects tagged with it.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted tag has not been added.
 #[receive(
     contract = "overlay-projects",
-    name = "upgrade",
-    parameter = "UpgradeParams",
-    mutable
+    name = "remove_tag",
+    parameter = "RemoveTagParams",
+    mutable,
+    error = "Error"
 )]
-fn contract_upgrade<S: HasStateApi>(
+fn contract_remove_tag<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
     host: &mut impl HasHost<State<S>, StateApiType = S>,
-) -> ReceiveResult<()> {
-    ensure!(ctx.sender().matches_account(&ctx.owner()));
-    let params: UpgradeParams = ctx.parameter_cursor().get()?;
-    host.upgrade(params.module)?;
-    if let Some((func, parameter)) = params.migrate {
-        host.invoke_contract_raw(
-            &ctx.self_address(),
-            parameter.as_parameter(),
-            func.as_entrypoint_name(),
-            Amount::zero(),
-        )?;
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let params: RemoveTagParams = ctx.parameter_cursor().get()?;
+    let project_ids = state.tags.remove_and_get(&params.tag);
+    ensure!(project_ids.is_some(), Error::TagNotFound);
+    for project_id in project_ids.unwrap().iter() {
+        if let Some(mut project) = state.project.get_mut(project_id) {
+            project.tags.retain(|tag| *tag != params.tag);
+        }
     }
     Ok(())
 }
 
-/// View the admin state.
+/// Replace the tags assigned to the inputted project.
 ///
-/// Caller: Admin account only.
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * Any of the inputted tags has not been added.
 #[receive(
     contract = "overlay-projects",
-    name = "view_admin",
-    return_value = "ViewAdminRes"
+    name = "set_project_tags",
+    parameter = "SetProjectTagsParams",
+    mutable,
+    error = "Error"
 )]
-fn contract_view_admin<S: HasStateApi>(
+fn contract_set_project_tags<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
-    host: &impl HasHost<State<S>, StateApiType = S>,
-) -> ContractResult<ViewAdminRes> {
-    let state = host.state();
-    ensure!(
-        ctx.sender() == Address::Account(state.admin),
-        Error::InvalidCaller
-    );
-    Ok(ViewAdminRes {
-        admin: state.admin,
-        staking_contract_addr: state.staking_contract_addr,
-        user_contract_addr: state.user_contract_addr,
-    })
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: SetProjectTagsParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    params.tags.sort();
+    params.tags.dedup();
+    for tag in params.tags.iter() {
+        ensure!(state.tags.get(tag).is_some(), Error::TagNotFound);
+    }
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    let old_tags = core::mem::replace(&mut project.tags, params.tags.clone());
+    drop(project);
+
+    remove_from_tag_index(state, &params.project_id, &old_tags);
+    for tag in params.tags.iter() {
+        if let Some(mut project_ids) = state.tags.get_mut(tag) {
+            project_ids.push(params.project_id.clone());
+        }
+    }
+    Ok(())
+}
+
+/// Remove the inputted project id from the index of the inputted tags.
+fn remove_from_tag_index<S: HasStateApi>(
+    state: &mut State<S>,
+    project_id: &ProjectId,
+    tags: &[Tag],
+) {
+    for tag in tags.iter() {
+        if let Some(mut project_ids) = state.tags.get_mut(tag) {
+            project_ids.retain(|tagged| tagged != project_id);
+        }
+    }
 }
 
-/// View the project state.
+/// Add the inputted listener contract, which is notified of every project change.
 ///
-/// Caller: Any accounts / Any contracts
+/// Caller: current admin account.
 /// Reject if:
-/// * The inputted project id has not been registered.
+/// * Caller is not the current admin account.
+/// * The inputted entrypoint of the inputted contract has already been added.
+/// * `MAX_LISTENERS` listeners have already been added.
 #[receive(
     contract = "overlay-projects",
-    name = "view_project",
-    parameter = "ViewProjectParams",
-    return_value = "ProjectState"
+    name = "add_listener",
+    parameter = "AddListenerParams",
+    mutable,
+    error = "Error"
 )]
-fn contract_view_project<S: HasStateApi>(
+fn contract_add_listener<S: HasStateApi>(
     ctx: &impl HasReceiveContext,
-    host: &impl HasHost<State<S>, StateApiType = S>,
-) -> ContractResult<ViewProjectResponse> {
-    let params: ViewProjectParams = ctx.parameter_cursor().get()?;
-    let state = host.state();
-    let project = state.project.get(&params.project_id);
-    ensure!(project.is_some(), Error::ProjectNotFound);
-    let project = project.unwrap();
-    Ok(ViewProjectResponse {
-        owners: project.owners.clone(),
-        project_uri: project.project_uri.clone(),
-        pub_key: project.pub_key.clone(),
-        token_addr: project.token_addr.clone(),
-        seed_nft_addr: project.seed_nft_addr.clone(),
-        sale_addr: project.sale_addr.clone(),
-        status: project.status.clone(),
-    })
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let params: AddListenerParams = ctx.parameter_cursor().get()?;
+    ensure!(
+        !state.listeners.iter().any(|listener| {
+            listener.address == params.address && listener.entrypoint == params.entrypoint
+        }),
+        Error::ListenerAlreadyExists
+    );
+    ensure!(
+        state.listeners.len() < MAX_LISTENERS,
+        Error::TooManyListeners
+    );
+    state.listeners.push(params);
+    Ok(())
 }
 
-/// View all project states.
+/// Remove the inputted listener contract.
 ///
-/// Caller: Any accounts / Any contracts
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted entrypoint of the inputted contract has not been added.
 #[receive(
     contract = "overlay-projects",
-    name = "view_projects",
-    return_value = "ViewProjectsResponse"
+    name = "remove_listener",
+    parameter = "RemoveListenerParams",
+    mutable,
+    error = "Error"
 )]
-fn contract_view_projects<S: HasStateApi>(
-    _ctx: &impl HasReceiveContext,
-    host: &impl HasHost<State<S>, StateApiType = S>,
-) -> ContractResult<ViewProjectsResponse> {
-    let projects_state = &host.state().project;
-    let projects_state_response: ViewProjectsResponse = projects_state
-        .iter()
-        .map(|(project_id, project_state)| (project_id.clone(), project_state.clone()))
-        .collect();
-    Ok(projects_state_response)
+fn contract_remove_listener<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let params: RemoveListenerParams = ctx.parameter_cursor().get()?;
+    let index = state.listeners.iter().position(|listener| {
+        listener.address == params.address && listener.entrypoint == params.entrypoint
+    });
+    ensure!(index.is_some(), Error::ListenerNotFound);
+    state.listeners.remove(index.unwrap());
+    Ok(())
 }
 
-/// View all project ids.
+/// Add public key of the inputted project.
+/// Use `rotate_pub_key` to replace the active public key.
 ///
-/// Caller: Any accounts / Any contracts
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is Candidate
+/// * The inputted project already has an active public key.
 #[receive(
     contract = "overlay-projects",
-    name = "view_project_ids",
-    return_value = "ViewProjectIdsResponse"
+    name = "add_pub_key",
+    parameter = "AddPubKeyParams",
+    mutable,
+    error = "Error"
 )]
-fn contract_view_project_ids<S: HasStateApi>(
-    _ctx: &impl HasReceiveContext,
-    host: &impl HasHost<State<S>, StateApiType = S>,
-) -> ContractResult<ViewProjectIdsResponse> {
-    let projects_state = &host.state().project;
-    let project_ids_response: ViewProjectIdsResponse = projects_state
-        .iter()
-        .map(|(project_id, _project_state)| project_id.clone())
-        .collect();
-    Ok(project_ids_response)
+fn contract_add_pub_key<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: AddPubKeyParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        project.status != ProjectStatus::Candidate,
+        Error::InvalidStatus
+    );
+    ensure!(project.pub_key.is_none(), Error::PublicKeyAlreadyExists);
+    project.pub_key = Some(params.pub_key);
+    project.pub_keys.push(PubKeyRecord {
+        pub_key: params.pub_key,
+        activated_at: ctx.metadata().slot_time(),
+        revoked_at: None,
+    });
+    Ok(())
 }
 
-/// implements Debug for State inside test functions.
-/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
-/// (e.g. when launched by `cargo concordium test`)
-#[concordium_cfg_test]
-impl<S: HasStateApi> Debug for State<S> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "admin: {:?}, staking_contract_addr: {:?}, user_contract_addr: {:?}, ",
-            self.admin, self.staking_contract_addr, self.user_contract_addr,
-        )?;
-        for (project_id, project_state) in self.project.iter() {
-            write!(
-                f,
-                "project_id: {:?}, project_state: {:?}, ",
-                project_id, project_state
-            )?;
-        }
-        Ok(())
+/// Revoke the active public key of the inputted project and activate the inputted one.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * The inputted project has no active public key.
+#[receive(
+    contract = "overlay-projects",
+    name = "rotate_pub_key",
+    parameter = "RotatePubKeyParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_rotate_pub_key<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: RotatePubKeyParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(project.pub_key.is_some(), Error::PublicKeyNotFound);
+    let now = ctx.metadata().slot_time();
+    if let Some(record) = project.pub_keys.last_mut() {
+        record.revoked_at = Some(now);
     }
+    project.pub_key = Some(params.pub_key);
+    project.pub_keys.push(PubKeyRecord {
+        pub_key: params.pub_key,
+        activated_at: now,
+        revoked_at: None,
+    });
+    Ok(())
 }
 
-/// implements PartialEq for `claim_eq` inside test functions.
-/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
-/// (e.g. when launched by `cargo concordium test`)
-#[concordium_cfg_test]
-impl<S: HasStateApi> PartialEq for State<S> {
-    fn eq(&self, other: &Self) -> bool {
-        if self.admin != other.admin {
-            return false;
-        }
-        if self.staking_contract_addr != other.staking_contract_addr {
-            return false;
-        }
-        if self.user_contract_addr != other.user_contract_addr {
-            return false;
-        }
-        if self.project.iter().count() != other.project.iter().count() {
-            return false;
-        }
-        for (my_project_id, my_project_state) in self.project.iter() {
-            let other_project_state = other.project.get(&my_project_id);
-            if other_project_state.is_none() {
-                return false;
-            }
-            let other_project_state = other_project_state.unwrap();
-            if my_project_state.clone() != other_project_state.clone() {
-                return false;
-            }
-        }
-        true
+/// Revoke the active public key of the inputted project, e.g. when the key has been compromised.
+///
+/// Caller: current admin account or owner of the project.
+/// Reject if:
+/// * Caller is neither the current admin account nor the owner of the project.
+/// * The inputted project id has not been registered.
+/// * The inputted project has no active public key.
+#[receive(
+    contract = "overlay-projects",
+    name = "revoke_pub_key",
+    parameter = "RevokePubKeyParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_revoke_pub_key<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let mut params: RevokePubKeyParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let state = host.state_mut();
+    let admin = state.admin;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        ctx.invoker() == admin || project.owners.contains(&ctx.invoker()),
+        Error::InvalidCaller
+    );
+    ensure!(project.pub_key.is_some(), Error::PublicKeyNotFound);
+    let now = ctx.metadata().slot_time();
+    if let Some(record) = project.pub_keys.last_mut() {
+        record.revoked_at = Some(now);
     }
+    project.pub_key = None;
+    Ok(())
+}
 
-    fn ne(&self, other: &Self) -> bool {
-        !self.eq(other)
+/// Apply an action to the inputted project, authorised by a signature of the project public key
+/// instead of the caller account. The nonce of the project is incremented on success.
+///
+/// Caller: Any accounts / Any contracts
+/// Reject if:
+/// * The inputted project id has not been registered.
+/// * The inputted project has no public key.
+/// * The signed contract address is not this contract.
+/// * The signed nonce does not match with the current nonce of the project.
+/// * The signature is not valid for the project public key.
+/// * The action is UpdateOwners and the inputted owners are empty, have duplicates or exceed the
+///   max number of owners.
+/// * The action is UpdateOwners and the inputted project state is Candidate.
+/// * The action is UpdateProjectUri, the inputted project state is not Candidate and the inputted
+///   project URI has no hash.
+#[receive(
+    contract = "overlay-projects",
+    name = "apply_signed_action",
+    parameter = "ApplySignedActionParams",
+    mutable,
+    error = "Error",
+    crypto_primitives
+)]
+fn contract_apply_signed_action<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    crypto_primitives: &impl HasCryptoPrimitives,
+) -> ContractResult<()> {
+    let params: ApplySignedActionParams = ctx.parameter_cursor().get()?;
+    let message = params.message;
+    ensure!(
+        message.contract_address == ctx.self_address(),
+        Error::WrongContract
+    );
+    let state = host.state_mut();
+    if let SignedProjectAction::UpdateOwners(owners) = &message.action {
+        validate_owners(state, owners)?;
+    }
+    // the signature covers the project id as signed, so it is normalized only for the lookup.
+    let project = state
+        .project
+        .get_mut(&normalize_project_id(&message.project_id)?);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    let pub_key = project.pub_key.ok_or(Error::PublicKeyNotFound)?;
+    ensure!(
+        message.nonce == project.signed_action_nonce,
+        Error::InvalidNonce
+    );
+    ensure!(
+        crypto_primitives.verify_ed25519_signature(pub_key, params.signature, &to_bytes(&message)),
+        Error::InvalidSignature
+    );
+    project.signed_action_nonce += 1;
+    match message.action {
+        SignedProjectAction::UpdateProjectUri(project_uri) => {
+            project.check_project_uri(&project_uri)?;
+            project.pending_project_uri = Some(project_uri);
+        },
+        SignedProjectAction::UpdateOwners(owners) => {
+            ensure!(
+                project.status != ProjectStatus::Candidate,
+                Error::InvalidStatus
+            );
+            project.replace_owners(owners);
+        },
     }
+    Ok(())
 }
 
-/// implements Debug for ProjectState inside test functions.
-/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
-/// (e.g. when launched by `cargo concordium test`)
-#[concordium_cfg_test]
-impl Debug for ProjectState {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "project_uri: {:?}, owners: {:?}, pub_key: {:?}, token_addr: {:?}, seed_nft_addr: {:?}, sale_addr: {:?}, status: {:?}",
-            self.project_uri, self.owners, self.pub_key, self.token_addr, self.seed_nft_addr, self.sale_addr, self.status
-        )
+/// Update owners of the inputted project, discarding its pending owner proposal.
+/// This is kept for admin to recover projects whose owners can not reach the owner threshold.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted owners are empty, have duplicates or exceed the max number of owners.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is Candidate
+#[receive(
+    contract = "overlay-projects",
+    name = "update_owners",
+    parameter = "UpdateOwnersParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_update_owners<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: UpdateOwnersParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    validate_owners(state, &params.owners)?;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        project.status != ProjectStatus::Candidate,
+        Error::InvalidStatus
+    );
+    project.replace_owners(params.owners);
+    Ok(())
+}
+
+/// Move the inputted project to the archive, excluding it from listings and tag index.
+/// Archived projects are still viewable through `view_project`.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * Invoking a listener contract with the Revert failure policy fails.
+#[receive(
+    contract = "overlay-projects",
+    name = "archive_project",
+    parameter = "ArchiveProjectParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_archive_project<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: ArchiveProjectParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = state.project.remove_and_get(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let project = project.unwrap();
+    if project.status == ProjectStatus::Candidate {
+        release_candidate(state, project.curated_by);
     }
+    remove_from_tag_index(state, &params.project_id, &project.tags);
+    let status = project.status.clone();
+    state.archive.insert(params.project_id.clone(), project);
+    notify_listeners(
+        host,
+        ProjectNotification {
+            project_id: params.project_id,
+            status,
+            event: ProjectEvent::Archived,
+        },
+    )
+}
+
+/// Delete the inputted project along with its whitelist and metadata history, and let the
+/// overlay-users contract drop curation entries of the project.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is not Candidate.
+/// * Invoking `remove_project` of the overlay-users contract fails.
+/// * Invoking a listener contract with the Revert failure policy fails.
+#[receive(
+    contract = "overlay-projects",
+    name = "delete_project",
+    parameter = "DeleteProjectParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_delete_project<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: DeleteProjectParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = state.project.get(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let project = project.unwrap();
+    ensure!(
+        project.status == ProjectStatus::Candidate,
+        Error::InvalidStatus
+    );
+    let tags = project.tags.clone();
+    let curated_by = project.curated_by;
+    drop(project);
+    update_project_stats(state, &params.project_id, |stats| stats.rejected += 1);
+    release_candidate(state, curated_by);
+    remove_from_tag_index(state, &params.project_id, &tags);
+    state.project.remove(&params.project_id);
+    state.whitelist.remove(&params.project_id);
+    state.metadata_history.remove(&params.project_id);
+
+    let user_contract_addr = state.user_contract_addr;
+    let func = EntrypointName::new_unchecked("remove_project");
+    let remove_project_params = RemoveProjectParams {
+        project_id: params.project_id.clone(),
+    };
+    host.invoke_contract(
+        &user_contract_addr,
+        &remove_project_params,
+        func,
+        Amount::zero(),
+    )
+    .map_err(|_| Error::FailedInvokeUserContract)?;
+    notify_listeners(
+        host,
+        ProjectNotification {
+            project_id: params.project_id,
+            status: ProjectStatus::Candidate,
+            event: ProjectEvent::Deleted,
+        },
+    )
 }
 
-/// implements PartialEq for `claim_eq` inside test functions.
-/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
-/// (e.g. when launched by `cargo concordium test`)
-#[concordium_cfg_test]
-impl PartialEq for ProjectState {
-    fn eq(&self, other: &Self) -> bool {
-        self.project_uri == other.project_uri
-            && self.owners == other.owners
-            && self.pub_key == other.pub_key
-            && self.token_addr == other.token_addr
-            && self.seed_nft_addr == other.seed_nft_addr
-            && self.sale_addr == other.sale_addr
-            && self.status == other.status
-    }
+/// Merge the duplicate project into the surviving project.
+/// Tags of the duplicate project are added to the surviving project, and its project uri is taken
+/// over and recorded in the metadata history of the surviving project if the surviving project
+/// has none. Owners of the duplicate project are added to a Candidate surviving project, resetting
+/// its owner threshold to a majority. Owners of a surviving project past Candidate are kept, and
+/// can only be changed by admin through `update_owners`. The duplicate project is archived with
+/// its metadata history and a pointer to the surviving project, and the overlay-users contract
+/// re-points its curation entries.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project ids are the same.
+/// * Either of the inputted project ids has not been registered.
+/// * The duplicate project state is not Candidate.
+/// * The surviving project state is Candidate and the merged owners exceed the max number of
+///   owners.
+/// * Invoking `merge_project` of the overlay-users contract fails.
+/// * Invoking a listener contract with the Revert failure policy fails.
+#[receive(
+    contract = "overlay-projects",
+    name = "merge_projects",
+    parameter = "MergeProjectsParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_merge_projects<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: MergeProjectsParams = ctx.parameter_cursor().get()?;
+    params.keep = normalize_project_id(&params.keep)?;
+    params.drop = normalize_project_id(&params.drop)?;
+    ensure!(params.keep != params.drop, Error::MergeIntoSelf);
+    ensure!(
+        state.project.get(&params.keep).is_some(),
+        Error::ProjectNotFound
+    );
+    let dropped = state.project.remove_and_get(&params.drop);
+    ensure!(dropped.is_some(), Error::ProjectNotFound);
+    let mut dropped = dropped.unwrap();
+    ensure!(
+        dropped.status == ProjectStatus::Candidate,
+        Error::InvalidStatus
+    );
+    release_candidate(state, dropped.curated_by);
+    remove_from_tag_index(state, &params.drop, &dropped.tags);
+    state.whitelist.remove(&params.drop);
+
+    let kept = state.project.get(&params.keep).unwrap();
+    let merged_owners = if kept.status == ProjectStatus::Candidate {
+        let mut owners = kept.owners.clone();
+        for owner in dropped.owners.iter() {
+            if !owners.contains(owner) {
+                owners.push(*owner);
+            }
+        }
+        Some(owners)
+    } else {
+        None
+    };
+    drop(kept);
+    if let Some(owners) = &merged_owners {
+        validate_owners(state, owners)?;
+    }
+
+    let mut kept = state.project.get_mut(&params.keep).unwrap();
+    if let Some(owners) = merged_owners {
+        kept.replace_owners(owners);
+    }
+    let new_tags: Vec<Tag> = dropped
+        .tags
+        .iter()
+        .filter(|tag| !kept.tags.contains(tag))
+        .cloned()
+        .collect();
+    kept.tags.extend(new_tags.iter().cloned());
+    let taken_over_uri = if kept.project_uri.is_none() {
+        dropped.project_uri.clone()
+    } else {
+        None
+    };
+    if taken_over_uri.is_some() {
+        kept.project_uri = taken_over_uri.clone();
+    }
+    drop(kept);
+    for tag in new_tags.iter() {
+        if let Some(mut project_ids) = state.tags.get_mut(tag) {
+            project_ids.push(params.keep.clone());
+        }
+    }
+    if let Some(uri) = taken_over_uri {
+        let record = MetadataRecord {
+            uri,
+            timestamp: ctx.metadata().slot_time(),
+            changed_by: ctx.invoker(),
+        };
+        record_metadata(state, &params.keep, record);
+    }
+    dropped.merged_into = Some(params.keep.clone());
+    state.archive.insert(params.drop.clone(), dropped);
+
+    let user_contract_addr = state.user_contract_addr;
+    let func = EntrypointName::new_unchecked("merge_project");
+    let merge_project_params = MergeProjectParams {
+        from: params.drop.clone(),
+        into: params.keep.clone(),
+    };
+    host.invoke_contract(
+        &user_contract_addr,
+        &merge_project_params,
+        func,
+        Amount::zero(),
+    )
+    .map_err(|_| Error::FailedInvokeUserContract)?;
+    notify_listeners(
+        host,
+        ProjectNotification {
+            project_id: params.drop,
+            status: ProjectStatus::Candidate,
+            event: ProjectEvent::Merged(params.keep),
+        },
+    )
+}
+
+/// Propose new owners and owner threshold of the inputted project, counting as the caller's
+/// approval. The proposal is applied once approved by `owner_threshold` of the current owners,
+/// replacing any previous proposal.
+///
+/// Caller: Owner of the project.
+/// Reject if:
+/// * The inputted owners are empty, have duplicates or exceed the max number of owners.
+/// * The inputted owner threshold is zero or exceeds the number of the inputted owners.
+/// * The inputted project id has not been registered.
+/// * Caller is not the owner of the project.
+/// * The inputted project state is Candidate.
+#[receive(
+    contract = "overlay-projects",
+    name = "propose_owners",
+    parameter = "ProposeOwnersParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_propose_owners<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    let mut params: ProposeOwnersParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    validate_owners(state, &params.owners)?;
+    ensure!(
+        params.owner_threshold > 0 && params.owner_threshold as usize <= params.owners.len(),
+        Error::InvalidOwnerThreshold
+    );
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        project.owners.contains(&ctx.invoker()),
+        Error::InvalidCaller
+    );
+    ensure!(
+        project.status != ProjectStatus::Candidate,
+        Error::InvalidStatus
+    );
+    project.owner_proposal = Some(OwnerProposal {
+        owners: params.owners,
+        owner_threshold: params.owner_threshold,
+        approvals: vec![ctx.invoker()],
+    });
+    apply_owner_proposal(&mut project);
+    Ok(())
+}
+
+/// Approve the owner proposal of the inputted project, applying it once approved by
+/// `owner_threshold` of the current owners.
+///
+/// Caller: Owner of the project.
+/// Reject if:
+/// * The inputted project id has not been registered.
+/// * Caller is not the owner of the project.
+/// * The inputted project has no owner proposal.
+/// * Caller has already approved the owner proposal.
+#[receive(
+    contract = "overlay-projects",
+    name = "approve_owners",
+    parameter = "ApproveOwnersParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_approve_owners<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let mut params: ApproveOwnersParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = host.state_mut().project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        project.owners.contains(&ctx.invoker()),
+        Error::InvalidCaller
+    );
+    let proposal = project.owner_proposal.as_mut();
+    ensure!(proposal.is_some(), Error::OwnerProposalNotFound);
+    let proposal = proposal.unwrap();
+    ensure!(
+        !proposal.approvals.contains(&ctx.invoker()),
+        Error::AlreadyApproved
+    );
+    proposal.approvals.push(ctx.invoker());
+    apply_owner_proposal(&mut project);
+    Ok(())
+}
+
+/// Apply the owner proposal of the inputted project if it has enough approvals.
+fn apply_owner_proposal(project: &mut ProjectState) {
+    let proposal = match project.owner_proposal.as_ref() {
+        Some(proposal) if proposal.approvals.len() >= project.owner_threshold as usize => {
+            proposal.clone()
+        },
+        _ => return,
+    };
+    project.replace_owners(proposal.owners);
+    project.owner_threshold = proposal.owner_threshold;
+}
+
+/// Update seed NFT address of the inputted project.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is not Whitelist
+#[receive(
+    contract = "overlay-projects",
+    name = "add_seed_sale",
+    parameter = "AddSeedSaleParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_add_seed_sale<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: AddSeedSaleParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        project.status == ProjectStatus::Whitelist,
+        Error::InvalidStatus
+    );
+    project.seed_nft_addr = Some(params.seed_nft_addr);
+    Ok(())
+}
+
+/// Update sale address of the inputted project.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is not Whitelist.
+/// * The inputted project seed NFT address is not None.
+#[receive(
+    contract = "overlay-projects",
+    name = "add_sale",
+    parameter = "AddSaleParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_add_sale<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: AddSaleParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        project.status == ProjectStatus::Whitelist,
+        Error::InvalidStatus
+    );
+    ensure!(project.seed_nft_addr.is_none(), Error::ShouldNotBeTON);
+    project.sale_addr = Some(params.sale_addr);
+    Ok(())
+}
+
+/// Update the inputted project status as OnSale.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is not Whitelist.
+/// * Invoking `update_project_status` of the overlay-users contract fails.
+#[receive(
+    contract = "overlay-projects",
+    name = "start_sale",
+    parameter = "StartSaleParams",
+    mutable
+)]
+fn contract_start_sale<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: StartSaleParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        project.status == ProjectStatus::Whitelist,
+        Error::InvalidStatus
+    );
+    project.status = ProjectStatus::OnSale;
+    drop(project);
+    notify_status_change(host, params.project_id, ProjectStatus::OnSale)
+}
+
+/// Update the inputted project status as SaleClosed.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is not OnSale.
+/// * Invoking `update_project_status` of the overlay-users contract fails.
+#[receive(
+    contract = "overlay-projects",
+    name = "close_sale",
+    parameter = "CloseSaleParams",
+    mutable
+)]
+fn contract_close_sale<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: CloseSaleParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        project.status == ProjectStatus::OnSale,
+        Error::InvalidStatus
+    );
+    project.status = ProjectStatus::SaleClosed;
+    drop(project);
+    notify_status_change(host, params.project_id, ProjectStatus::SaleClosed)
+}
+
+/// Update the inputted project status as TokenGenerated and record its token address.
+/// Only admin can do so, since a SaleClosed project can no longer be refunded afterwards.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is not SaleClosed.
+/// * The project already has a token address which differs from the inputted one.
+/// * Invoking `update_project_status` of the overlay-users contract fails.
+#[receive(
+    contract = "overlay-projects",
+    name = "generate_token",
+    parameter = "GenerateTokenParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_generate_token<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: GenerateTokenParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        project.status == ProjectStatus::SaleClosed,
+        Error::InvalidStatus
+    );
+    ensure!(
+        project
+            .token_addr
+            .map_or(true, |current| current == params.token_addr),
+        Error::TokenAddrConflict
+    );
+    project.token_addr = Some(params.token_addr);
+    project.token_generated_at = Some(ctx.metadata().slot_time());
+    project.status = ProjectStatus::TokenGenerated;
+    drop(project);
+    // sale closed rewards and stats are accrued only now, since the sale can be refunded until the
+    // token is generated.
+    let points = state.reward_points.sale_closed;
+    accrue_rewards(state, &params.project_id, points);
+    update_project_stats(state, &params.project_id, |stats| stats.sold_out += 1);
+    notify_status_change(host, params.project_id, ProjectStatus::TokenGenerated)
+}
+
+/// Update the inputted project status as Listed and record its DEX listing address.
+///
+/// Caller: current admin account or owner of the project.
+/// Reject if:
+/// * Caller is neither the current admin account nor the owner of the project.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is not TokenGenerated.
+/// * Invoking `update_project_status` of the overlay-users contract fails.
+#[receive(
+    contract = "overlay-projects",
+    name = "list_token",
+    parameter = "ListTokenParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_list_token<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let mut params: ListTokenParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let state = host.state_mut();
+    let admin = state.admin;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        ctx.invoker() == admin || project.owners.contains(&ctx.invoker()),
+        Error::InvalidCaller
+    );
+    ensure!(
+        project.status == ProjectStatus::TokenGenerated,
+        Error::InvalidStatus
+    );
+    project.listing_addr = Some(params.listing_addr);
+    project.listed_at = Some(ctx.metadata().slot_time());
+    project.status = ProjectStatus::Listed;
+    drop(project);
+    notify_status_change(host, params.project_id, ProjectStatus::Listed)
+}
+
+/// Update the inputted project status as Vesting and record its vesting contract address.
+///
+/// Caller: current admin account or owner of the project.
+/// Reject if:
+/// * Caller is neither the current admin account nor the owner of the project.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is not Listed.
+/// * The inputted vesting address differs from the one of the approved vesting schedule.
+/// * Invoking `update_project_status` of the overlay-users contract fails.
+#[receive(
+    contract = "overlay-projects",
+    name = "start_vesting",
+    parameter = "StartVestingParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_start_vesting<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let mut params: StartVestingParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let state = host.state_mut();
+    let admin = state.admin;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        ctx.invoker() == admin || project.owners.contains(&ctx.invoker()),
+        Error::InvalidCaller
+    );
+    ensure!(
+        project.status == ProjectStatus::Listed,
+        Error::InvalidStatus
+    );
+    if let Some(schedule) = &project.vesting_schedule {
+        ensure!(
+            schedule.vesting_contract_addr == params.vesting_addr,
+            Error::InvalidVestingSchedule
+        );
+    }
+    project.vesting_addr = Some(params.vesting_addr);
+    project.vesting_started_at = Some(ctx.metadata().slot_time());
+    project.status = ProjectStatus::Vesting;
+    drop(project);
+    notify_status_change(host, params.project_id, ProjectStatus::Vesting)
+}
+
+/// Update the inputted project status as Refunding and let the sale contract open refunds.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is neither OnSale nor SaleClosed.
+/// * The inputted project sale address is None.
+/// * Invoking `update_project_status` of the overlay-users contract fails.
+/// * Invoking `open_refund` of the sale contract fails.
+#[receive(
+    contract = "overlay-projects",
+    name = "start_refund",
+    parameter = "StartRefundParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_start_refund<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: StartRefundParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        project.status == ProjectStatus::OnSale || project.status == ProjectStatus::SaleClosed,
+        Error::InvalidStatus
+    );
+    let sale_addr = project.sale_addr.ok_or(Error::SaleNotFound)?;
+    project.status = ProjectStatus::Refunding;
+    drop(project);
+    notify_status_change(host, params.project_id.clone(), ProjectStatus::Refunding)?;
+
+    let func = EntrypointName::new_unchecked("open_refund");
+    let open_refund_params = OpenRefundParams {
+        project_id: params.project_id,
+    };
+    host.invoke_contract(&sale_addr, &open_refund_params, func, Amount::zero())
+        .map(|(_, _)| ())
+        .map_err(|_| Error::FailedInvokeSaleContract)
+}
+
+/// Update the inputted project status as Cancelled once the sale contract reports that all refunds
+/// have been completed.
+///
+/// Caller: sale contract of the project.
+/// Reject if:
+/// * The inputted project id has not been registered.
+/// * Caller is not the sale contract of the project.
+/// * The inputted project state is not Refunding.
+/// * Invoking `update_project_status` of the overlay-users contract fails.
+#[receive(
+    contract = "overlay-projects",
+    name = "complete_refund",
+    parameter = "CompleteRefundParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_complete_refund<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let mut params: CompleteRefundParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let state = host.state_mut();
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        project.sale_addr.map(Address::Contract) == Some(ctx.sender()),
+        Error::InvalidCaller
+    );
+    ensure!(
+        project.status == ProjectStatus::Refunding,
+        Error::InvalidStatus
+    );
+    project.status = ProjectStatus::Cancelled;
+    drop(project);
+    update_project_stats(state, &params.project_id, |stats| stats.rejected += 1);
+    notify_status_change(host, params.project_id, ProjectStatus::Cancelled)
+}
+
+/// Update the maximum number of whitelist lottery entrants of the inputted project.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is neither Candidate nor Whitelist.
+/// * The inputted maximum is zero or exceeds `MAX_WHITELIST_ENTRANTS`.
+#[receive(
+    contract = "overlay-projects",
+    name = "set_whitelist_cap",
+    parameter = "SetWhitelistCapParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_set_whitelist_cap<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: SetWhitelistCapParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    ensure!(
+        params.max_entrants > 0 && params.max_entrants <= MAX_WHITELIST_ENTRANTS,
+        Error::InvalidWhitelistCap
+    );
+    let project = state.project.get(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let project = project.unwrap();
+    ensure!(
+        project.status == ProjectStatus::Candidate || project.status == ProjectStatus::Whitelist,
+        Error::InvalidStatus
+    );
+    let mut whitelist = state
+        .whitelist
+        .entry(params.project_id)
+        .or_insert_with(WhitelistState::default);
+    whitelist.max_entrants = params.max_entrants;
+    Ok(())
+}
+
+/// Register the caller for the whitelist lottery of the inputted project.
+///
+/// Caller: Any accounts which are overlay users.
+/// Reject if:
+/// * Caller is a contract.
+/// * Caller is not an overlay user.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is not Whitelist.
+/// * The registration deadline of the lottery has passed.
+/// * Caller has already registered for the project.
+/// * The number of entrants has reached the maximum.
+#[receive(
+    contract = "overlay-projects",
+    name = "register_whitelist",
+    parameter = "RegisterWhitelistParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_register_whitelist<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let mut params: RegisterWhitelistParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let sender_account = match ctx.sender() {
+        Address::Contract(_) => bail!(Error::OnlyAccount),
+        Address::Account(account_address) => account_address,
+    };
+
+    // let's check the caller is an overlay user.
+    let func = EntrypointName::new_unchecked("view_user");
+    let user_contract_addr = host.state().user_contract_addr;
+    let view_user_params = ViewUserParams {
+        addr: sender_account,
+    };
+    let _: UserStateResponse = host
+        .invoke_contract_read_only(&user_contract_addr, &view_user_params, func, Amount::zero())
+        .map_err(|_| Error::FailedInvokeUserContractView)?
+        .ok_or(Error::FailedInvokeUserContractView)?
+        .get()?;
+
+    let state = host.state_mut();
+    let project = state.project.get(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    ensure!(
+        project.unwrap().status == ProjectStatus::Whitelist,
+        Error::InvalidStatus
+    );
+    let mut whitelist = state
+        .whitelist
+        .entry(params.project_id)
+        .or_insert_with(WhitelistState::default);
+    if let Some(lottery) = &whitelist.lottery {
+        ensure!(
+            ctx.metadata().slot_time() < lottery.registration_deadline,
+            Error::RegistrationClosed
+        );
+    }
+    ensure!(
+        !whitelist.entrants.contains(&sender_account),
+        Error::AlreadyRegistered
+    );
+    ensure!(
+        (whitelist.entrants.len() as u32) < whitelist.max_entrants,
+        Error::WhitelistFull
+    );
+    whitelist.entrants.push(sender_account);
+    Ok(())
+}
+
+/// Commit the hash of the seed used to draw the whitelist lottery of the inputted project, together
+/// with the registration deadline after which the seed will be revealed.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is not Whitelist.
+/// * The inputted winner count is zero.
+/// * The inputted registration deadline has already passed.
+/// * The lottery has already been committed.
+#[receive(
+    contract = "overlay-projects",
+    name = "commit_lottery",
+    parameter = "CommitLotteryParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_commit_lottery<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: CommitLotteryParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    ensure!(params.winner_count > 0, Error::InvalidWinnerCount);
+    let project = state.project.get(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    ensure!(
+        project.unwrap().status == ProjectStatus::Whitelist,
+        Error::InvalidStatus
+    );
+    ensure!(
+        ctx.metadata().slot_time() < params.registration_deadline,
+        Error::RegistrationClosed
+    );
+    let mut whitelist = state
+        .whitelist
+        .entry(params.project_id)
+        .or_insert_with(WhitelistState::default);
+    ensure!(whitelist.lottery.is_none(), Error::LotteryAlreadyCommitted);
+    whitelist.lottery = Some(Lottery {
+        seed_hash: params.seed_hash,
+        winner_count: params.winner_count,
+        registration_deadline: params.registration_deadline,
+        seed: None,
+        winners: Vec::new(),
+    });
+    Ok(())
+}
+
+/// Reveal the committed seed of the whitelist lottery of the inputted project and draw winners.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The lottery of the inputted project has not been committed.
+/// * The lottery has already been revealed.
+/// * The registration deadline has not passed yet.
+/// * The hash of the inputted seed does not match with the committed one.
+#[receive(
+    contract = "overlay-projects",
+    name = "reveal_lottery",
+    parameter = "RevealLotteryParams",
+    mutable,
+    error = "Error",
+    crypto_primitives
+)]
+fn contract_reveal_lottery<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    crypto_primitives: &impl HasCryptoPrimitives,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: RevealLotteryParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let whitelist = state.whitelist.get_mut(&params.project_id);
+    ensure!(whitelist.is_some(), Error::LotteryNotCommitted);
+    let mut whitelist = whitelist.unwrap();
+    let WhitelistState {
+        entrants, lottery, ..
+    } = &mut *whitelist;
+    let lottery = lottery.as_mut().ok_or(Error::LotteryNotCommitted)?;
+    ensure!(lottery.seed.is_none(), Error::LotteryAlreadyRevealed);
+    ensure!(
+        ctx.metadata().slot_time() >= lottery.registration_deadline,
+        Error::RegistrationNotClosed
+    );
+    ensure!(
+        crypto_primitives.hash_sha2_256(&params.seed).0 == lottery.seed_hash,
+        Error::InvalidLotterySeed
+    );
+    lottery.winners = draw_winners(
+        crypto_primitives,
+        &params.seed,
+        entrants,
+        lottery.winner_count,
+    );
+    lottery.seed = Some(params.seed);
+    Ok(())
+}
+
+/// Update allocation tiers of the inputted project.
+/// The tiers must be sorted by `min_stake` in strictly ascending order.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is neither Candidate nor Whitelist.
+/// * The inputted tiers are not sorted by `min_stake` in strictly ascending order.
+#[receive(
+    contract = "overlay-projects",
+    name = "set_allocation_tiers",
+    parameter = "SetAllocationTiersParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_set_allocation_tiers<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: SetAllocationTiersParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        project.status == ProjectStatus::Candidate || project.status == ProjectStatus::Whitelist,
+        Error::InvalidStatus
+    );
+    ensure!(
+        params
+            .tiers
+            .windows(2)
+            .all(|tiers| tiers[0].min_stake < tiers[1].min_stake),
+        Error::InvalidAllocationTiers
+    );
+    project.allocation_tiers = params.tiers;
+    Ok(())
+}
+
+/// Propose the vesting schedule of the inputted project, which is applied once approved by admin.
+///
+/// Caller: Owner of the project, or anyone with a `permit` message signed by the owner.
+/// Reject if:
+/// * Caller is not the owner of the project.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is neither Candidate nor Whitelist.
+/// * The inputted vesting schedule is invalid.
+#[receive(
+    contract = "overlay-projects",
+    name = "propose_vesting_schedule",
+    parameter = "ProposeVestingScheduleParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_propose_vesting_schedule<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let params: ProposeVestingScheduleParams = ctx.parameter_cursor().get()?;
+    propose_vesting_schedule(host.state_mut(), ctx.invoker(), params)
+}
+
+/// Propose the vesting schedule of the inputted project on behalf of the inputted owner.
+fn propose_vesting_schedule<S: HasStateApi>(
+    state: &mut State<S>,
+    owner: AccountAddress,
+    mut params: ProposeVestingScheduleParams,
+) -> ContractResult<()> {
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(project.owners.contains(&owner), Error::InvalidCaller);
+    ensure!(
+        project.status == ProjectStatus::Candidate || project.status == ProjectStatus::Whitelist,
+        Error::InvalidStatus
+    );
+    ensure!(params.schedule.is_valid(), Error::InvalidVestingSchedule);
+    project.pending_vesting_schedule = Some(params.schedule);
+    Ok(())
+}
+
+/// Approve the proposed vesting schedule of the inputted project.
+///
+/// Caller: current admin account.
+/// Reject if:
+/// * Caller is not the current admin account.
+/// * The inputted project id has not been registered.
+/// * The inputted project state is neither Candidate nor Whitelist.
+/// * No vesting schedule has been proposed for the inputted project.
+#[receive(
+    contract = "overlay-projects",
+    name = "approve_vesting_schedule",
+    parameter = "ApproveVestingScheduleParams",
+    mutable,
+    error = "Error"
+)]
+fn contract_approve_vesting_schedule<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<()> {
+    let state = host.state_mut();
+    ensure!(ctx.invoker() == state.admin, Error::InvalidCaller);
+    let mut params: ApproveVestingScheduleParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = state.project.get_mut(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let mut project = project.unwrap();
+    ensure!(
+        project.status == ProjectStatus::Candidate || project.status == ProjectStatus::Whitelist,
+        Error::InvalidStatus
+    );
+    let schedule = project.pending_vesting_schedule.take();
+    ensure!(schedule.is_some(), Error::VestingScheduleNotProposed);
+    project.vesting_schedule = schedule;
+    Ok(())
+}
+
+/// Invoke an owner action with a message signed by the owner account, so that anyone can sponsor
+/// the transaction fee on behalf of the owner as defined in CIS-3 standard.
+/// The message hash to be signed is `sha256(signer || [0u8; 8] || message)`, so that the signed
+/// bytes cannot be confused with a transaction of the signer account.
+///
+/// Caller: Any accounts / Any contracts
+/// Reject if:
+/// * The signed contract address is not this contract.
+/// * The message has been expired.
+/// * The signed nonce does not match with the current nonce of the signer.
+/// * The signature is not valid for the signer account.
+/// * The signed entrypoint is not one of `SUPPORTS_PERMIT_ENTRYPOINTS`.
+/// * The signed action is rejected on behalf of the signer.
+#[receive(
+    contract = "overlay-projects",
+    name = "permit",
+    parameter = "PermitParams",
+    mutable,
+    error = "Error",
+    crypto_primitives,
+    enable_logger
+)]
+fn contract_permit<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+    logger: &mut impl HasLogger,
+    crypto_primitives: &impl HasCryptoPrimitives,
+) -> ContractResult<()> {
+    let params: PermitParams = ctx.parameter_cursor().get()?;
+    let message = params.message;
+    ensure!(
+        message.contract_address == ctx.self_address(),
+        Error::WrongContract
+    );
+    ensure!(
+        message.timestamp > ctx.metadata().slot_time(),
+        Error::Expired
+    );
+    let nonce = host
+        .state()
+        .nonces
+        .get(&params.signer)
+        .map_or(0, |nonce| *nonce);
+    ensure!(message.nonce == nonce, Error::InvalidNonce);
+
+    let message_hash = crypto_primitives
+        .hash_sha2_256(&[&params.signer.0[..], &[0u8; 8][..], &to_bytes(&message)[..]].concat())
+        .0;
+    let is_valid = host
+        .check_account_signature(params.signer, &params.signature, &message_hash)
+        .map_err(|_| Error::InvalidSignature)?;
+    ensure!(is_valid, Error::InvalidSignature);
+
+    apply_permit_message(host.state_mut(), params.signer, &message)?;
+    logger.log(&NonceEvent {
+        nonce,
+        sponsoree: params.signer,
+    })?;
+    Ok(())
+}
+
+/// Consume the nonce of the inputted signer and invoke the signed entrypoint on behalf of the
+/// signer, once the signature of the message has been checked.
+fn apply_permit_message<S: HasStateApi>(
+    state: &mut State<S>,
+    signer: AccountAddress,
+    message: &PermitMessage,
+) -> ContractResult<()> {
+    state.nonces.insert(signer, message.nonce + 1);
+    let entry_point = message.entry_point.as_entrypoint_name();
+    if entry_point == SUPPORTS_PERMIT_ENTRYPOINTS[0] {
+        let action_params: AddTokenAddrParams = from_bytes(&message.payload)?;
+        add_token_addr(state, signer, action_params)
+    } else if entry_point == SUPPORTS_PERMIT_ENTRYPOINTS[1] {
+        let action_params: ProposeVestingScheduleParams = from_bytes(&message.payload)?;
+        propose_vesting_schedule(state, signer, action_params)
+    } else if entry_point == SUPPORTS_PERMIT_ENTRYPOINTS[2] {
+        let action_params: ProposeProjectUriParams = from_bytes(&message.payload)?;
+        propose_project_uri(state, signer, action_params)
+    } else if entry_point == SUPPORTS_PERMIT_ENTRYPOINTS[3] {
+        let action_params: ProposeProjectProfileParams = from_bytes(&message.payload)?;
+        propose_project_profile(state, signer, action_params)
+    } else {
+        bail!(Error::UnsupportedEntrypoint)
+    }
+}
+
+/// Smart contract module upgrade function.
+/// For more information see https://developer.concordium.software/en/mainnet/smart-contracts/guides/upgradeable-contract.html#guide-upgradable-contract
+/// The new module must be able to read the current state, since no migration is run unless
+/// `migrate` is inputted. Instances deployed before the post-sale stages were added have another
+/// layout of `State` and `ProjectState`, so they cannot be upgraded and need a fresh deployment.
+#[receive(
+    contract = "overlay-projects",
+    name = "upgrade",
+    parameter = "UpgradeParams",
+    mutable
+)]
+fn contract_upgrade<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &mut impl HasHost<State<S>, StateApiType = S>,
+) -> ReceiveResult<()> {
+    ensure!(ctx.sender().matches_account(&ctx.owner()));
+    let params: UpgradeParams = ctx.parameter_cursor().get()?;
+    host.upgrade(params.module)?;
+    if let Some((func, parameter)) = params.migrate {
+        host.invoke_contract_raw(
+            &ctx.self_address(),
+            parameter.as_parameter(),
+            func.as_entrypoint_name(),
+            Amount::zero(),
+        )?;
+    }
+    Ok(())
+}
+
+/// View the admin state.
+///
+/// Caller: Admin account only.
+#[receive(
+    contract = "overlay-projects",
+    name = "view_admin",
+    return_value = "ViewAdminRes"
+)]
+fn contract_view_admin<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewAdminRes> {
+    let state = host.state();
+    ensure!(
+        ctx.sender() == Address::Account(state.admin),
+        Error::InvalidCaller
+    );
+    Ok(ViewAdminRes {
+        admin: state.admin,
+        staking_contract_addr: state.staking_contract_addr,
+        user_contract_addr: state.user_contract_addr,
+    })
+}
+
+/// View the project state, including archived projects.
+///
+/// Caller: Any accounts / Any contracts
+/// Reject if:
+/// * The inputted project id has not been registered.
+#[receive(
+    contract = "overlay-projects",
+    name = "view_project",
+    parameter = "ViewProjectParams",
+    return_value = "ProjectState"
+)]
+fn contract_view_project<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewProjectResponse> {
+    let mut params: ViewProjectParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let state = host.state();
+    let project = state
+        .project
+        .get(&params.project_id)
+        .or_else(|| state.archive.get(&params.project_id));
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let project = project.unwrap();
+    Ok(ViewProjectResponse {
+        owners: project.owners.clone(),
+        project_uri: project.project_uri.clone(),
+        pub_key: project.pub_key,
+        token_addr: project.token_addr,
+        seed_nft_addr: project.seed_nft_addr,
+        sale_addr: project.sale_addr,
+        status: project.status.clone(),
+        token_generated_at: project.token_generated_at,
+        listing_addr: project.listing_addr,
+        listed_at: project.listed_at,
+        vesting_addr: project.vesting_addr,
+        vesting_started_at: project.vesting_started_at,
+        vesting_schedule: project.vesting_schedule.clone(),
+        pending_vesting_schedule: project.pending_vesting_schedule.clone(),
+        allocation_tiers: project.allocation_tiers.clone(),
+        signed_action_nonce: project.signed_action_nonce,
+        pub_keys: project.pub_keys.clone(),
+        pending_project_uri: project.pending_project_uri.clone(),
+        profile: project.profile.clone(),
+        pending_profile: project.pending_profile.clone(),
+        tags: project.tags.clone(),
+        owner_threshold: project.owner_threshold,
+        owner_proposal: project.owner_proposal.clone(),
+        merged_into: project.merged_into.clone(),
+        curated_by: project.curated_by,
+        validated_by: project.validated_by,
+    })
+}
+
+/// View the project uri history of the inputted project, from the oldest record.
+///
+/// Caller: Any accounts / Any contracts
+/// Reject if:
+/// * The inputted project id has not been registered.
+#[receive(
+    contract = "overlay-projects",
+    name = "view_project_metadata_history",
+    parameter = "ViewProjectMetadataHistoryParams",
+    return_value = "ViewProjectMetadataHistoryResponse"
+)]
+fn contract_view_project_metadata_history<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewProjectMetadataHistoryResponse> {
+    let mut params: ViewProjectMetadataHistoryParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let state = host.state();
+    ensure!(
+        state.project.get(&params.project_id).is_some()
+            || state.archive.get(&params.project_id).is_some(),
+        Error::ProjectNotFound
+    );
+    let history = state.metadata_history.get(&params.project_id);
+    Ok(history.map_or(Vec::new(), |history| {
+        history
+            .iter()
+            .skip(params.from as usize)
+            .take(params.limit as usize)
+            .cloned()
+            .collect()
+    }))
+}
+
+/// View all tags added by admin.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-projects",
+    name = "view_tags",
+    return_value = "ViewTagsResponse"
+)]
+fn contract_view_tags<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewTagsResponse> {
+    Ok(host
+        .state()
+        .tags
+        .iter()
+        .map(|(tag, _)| tag.clone())
+        .collect())
+}
+
+/// View the stats of the projects curated by the inputted account.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-projects",
+    name = "view_curator_stats",
+    parameter = "ViewStatsParams",
+    return_value = "ViewStatsResponse"
+)]
+fn contract_view_curator_stats<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewStatsResponse> {
+    let params: ViewStatsParams = ctx.parameter_cursor().get()?;
+    Ok(host
+        .state()
+        .curator_stats
+        .get(&params.account)
+        .map_or_else(ProjectStats::default, |stats| stats.clone()))
+}
+
+/// View the stats of the projects validated by the inputted account.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-projects",
+    name = "view_validator_stats",
+    parameter = "ViewStatsParams",
+    return_value = "ViewStatsResponse"
+)]
+fn contract_view_validator_stats<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewStatsResponse> {
+    let params: ViewStatsParams = ctx.parameter_cursor().get()?;
+    Ok(host
+        .state()
+        .validator_stats
+        .get(&params.account)
+        .map_or_else(ProjectStats::default, |stats| stats.clone()))
+}
+
+/// View the number of projects the inputted curator can still curate through `curate_project`.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-projects",
+    name = "view_curation_quota",
+    parameter = "ViewCurationQuotaParams",
+    return_value = "ViewCurationQuotaResponse"
+)]
+fn contract_view_curation_quota<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewCurationQuotaResponse> {
+    let params: ViewCurationQuotaParams = ctx.parameter_cursor().get()?;
+    Ok(remaining_curation_quota(
+        host.state(),
+        params.account,
+        ctx.metadata().slot_time(),
+    ))
+}
+
+/// View the listener contracts notified of project status changes, in order of registration.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-projects",
+    name = "view_listeners",
+    return_value = "ViewListenersResponse"
+)]
+fn contract_view_listeners<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewListenersResponse> {
+    Ok(host.state().listeners.clone())
+}
+
+/// View the reward points of the inputted account.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-projects",
+    name = "view_rewards",
+    parameter = "ViewRewardsParams",
+    return_value = "ViewRewardsResponse"
+)]
+fn contract_view_rewards<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewRewardsResponse> {
+    let params: ViewRewardsParams = ctx.parameter_cursor().get()?;
+    Ok(host
+        .state()
+        .rewards
+        .get(&params.account)
+        .map_or_else(RewardState::default, |reward| reward.clone()))
+}
+
+/// View the inputted range of ids of the projects tagged with the inputted tag, in order of
+/// tagging.
+///
+/// Caller: Any accounts / Any contracts
+/// Reject if:
+/// * The inputted tag has not been added.
+#[receive(
+    contract = "overlay-projects",
+    name = "view_projects_by_tag",
+    parameter = "ViewProjectsByTagParams",
+    return_value = "ViewProjectsByTagResponse"
+)]
+fn contract_view_projects_by_tag<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewProjectsByTagResponse> {
+    let params: ViewProjectsByTagParams = ctx.parameter_cursor().get()?;
+    let project_ids = host.state().tags.get(&params.tag);
+    ensure!(project_ids.is_some(), Error::TagNotFound);
+    Ok(project_ids
+        .unwrap()
+        .iter()
+        .skip(params.from as usize)
+        .take(params.limit as usize)
+        .cloned()
+        .collect())
+}
+
+/// View all public keys the inputted project has ever had, with their validity periods, including
+/// archived projects.
+/// A signature made at time T is valid if T is within `[activated_at, revoked_at)` of its key.
+///
+/// Caller: Any accounts / Any contracts
+/// Reject if:
+/// * The inputted project id has not been registered.
+#[receive(
+    contract = "overlay-projects",
+    name = "view_pub_keys",
+    parameter = "ViewPubKeysParams",
+    return_value = "ViewPubKeysResponse"
+)]
+fn contract_view_pub_keys<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewPubKeysResponse> {
+    let mut params: ViewPubKeysParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let state = host.state();
+    let project = state
+        .project
+        .get(&params.project_id)
+        .or_else(|| state.archive.get(&params.project_id));
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    Ok(project.unwrap().pub_keys.clone())
+}
+
+/// View whether the inputted standards are supported by this contract as defined in CIS-0
+/// standard.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-projects",
+    name = "supports",
+    parameter = "SupportsParams",
+    return_value = "SupportsResponse"
+)]
+fn contract_supports<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    _host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<SupportsResponse> {
+    let params: SupportsParams = ctx.parameter_cursor().get()?;
+    let results = params
+        .queries
+        .iter()
+        .map(|standard| {
+            if SUPPORTS_STANDARDS.contains(&standard.id.as_str()) {
+                SupportResult::Support
+            } else {
+                SupportResult::NoSupport
+            }
+        })
+        .collect();
+    Ok(SupportsResponse { results })
+}
+
+/// View whether the inputted entrypoints can be invoked through `permit` function.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-projects",
+    name = "supportsPermit",
+    parameter = "SupportsPermitParams",
+    return_value = "SupportsPermitResponse"
+)]
+fn contract_supports_permit<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    _host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<SupportsPermitResponse> {
+    let params: SupportsPermitParams = ctx.parameter_cursor().get()?;
+    let results = params
+        .queries
+        .iter()
+        .map(|entry_point| {
+            if SUPPORTS_PERMIT_ENTRYPOINTS.contains(&entry_point.as_entrypoint_name()) {
+                SupportResult::Support
+            } else {
+                SupportResult::NoSupport
+            }
+        })
+        .collect();
+    Ok(SupportsPermitResponse { results })
+}
+
+/// View the current nonces of the inputted accounts used by `permit` function.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-projects",
+    name = "nonceOf",
+    parameter = "NonceOfParams",
+    return_value = "NonceOfResponse"
+)]
+fn contract_nonce_of<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<NonceOfResponse> {
+    let params: NonceOfParams = ctx.parameter_cursor().get()?;
+    let nonces = params
+        .queries
+        .iter()
+        .map(|account| host.state().nonces.get(account).map_or(0, |nonce| *nonce))
+        .collect();
+    Ok(NonceOfResponse { nonces })
+}
+
+/// View all project states.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-projects",
+    name = "view_projects",
+    return_value = "ViewProjectsResponse"
+)]
+fn contract_view_projects<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewProjectsResponse> {
+    let projects_state = &host.state().project;
+    let projects_state_response: ViewProjectsResponse = projects_state
+        .iter()
+        .map(|(project_id, project_state)| (project_id.clone(), project_state.clone()))
+        .collect();
+    Ok(projects_state_response)
+}
+
+/// View all project ids.
+///
+/// Caller: Any accounts / Any contracts
+#[receive(
+    contract = "overlay-projects",
+    name = "view_project_ids",
+    return_value = "ViewProjectIdsResponse"
+)]
+fn contract_view_project_ids<S: HasStateApi>(
+    _ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewProjectIdsResponse> {
+    let projects_state = &host.state().project;
+    let project_ids_response: ViewProjectIdsResponse = projects_state
+        .iter()
+        .map(|(project_id, _project_state)| project_id.clone())
+        .collect();
+    Ok(project_ids_response)
+}
+
+/// View the unlocked percentage of the project token at the inputted time in basis points.
+///
+/// Caller: Any accounts / Any contracts
+/// Reject if:
+/// * The inputted project id has not been registered.
+/// * The inputted project has no approved vesting schedule.
+#[receive(
+    contract = "overlay-projects",
+    name = "view_unlocked_percentage",
+    parameter = "ViewUnlockedPercentageParams",
+    return_value = "ViewUnlockedPercentageResponse"
+)]
+fn contract_view_unlocked_percentage<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewUnlockedPercentageResponse> {
+    let mut params: ViewUnlockedPercentageParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let project = host.state().project.get(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let project = project.unwrap();
+    let schedule = project
+        .vesting_schedule
+        .as_ref()
+        .ok_or(Error::VestingScheduleNotFound)?;
+    Ok(schedule.unlocked_basis_points(params.time))
+}
+
+/// View the whitelist lottery registration of the inputted project and account.
+///
+/// Caller: Any accounts / Any contracts
+/// Reject if:
+/// * The inputted project id has not been registered.
+#[receive(
+    contract = "overlay-projects",
+    name = "view_whitelist",
+    parameter = "ViewWhitelistParams",
+    return_value = "ViewWhitelistResponse"
+)]
+fn contract_view_whitelist<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewWhitelistResponse> {
+    let mut params: ViewWhitelistParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let state = host.state();
+    ensure!(
+        state.project.get(&params.project_id).is_some(),
+        Error::ProjectNotFound
+    );
+    let whitelist = state
+        .whitelist
+        .get(&params.project_id)
+        .map(|whitelist| whitelist.clone())
+        .unwrap_or_default();
+    Ok(ViewWhitelistResponse {
+        max_entrants: whitelist.max_entrants,
+        entrant_count: whitelist.entrants.len() as u32,
+        is_registered: whitelist.entrants.contains(&params.addr),
+    })
+}
+
+/// View the whitelist lottery result of the inputted project and account.
+/// The draw can be verified by hashing the revealed seed and re-running it on the entrants.
+///
+/// Caller: Any accounts / Any contracts
+/// Reject if:
+/// * The lottery of the inputted project has not been committed.
+#[receive(
+    contract = "overlay-projects",
+    name = "view_lottery_result",
+    parameter = "ViewLotteryResultParams",
+    return_value = "ViewLotteryResultResponse"
+)]
+fn contract_view_lottery_result<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewLotteryResultResponse> {
+    let mut params: ViewLotteryResultParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let whitelist = host.state().whitelist.get(&params.project_id);
+    ensure!(whitelist.is_some(), Error::LotteryNotCommitted);
+    let whitelist = whitelist.unwrap();
+    let lottery = whitelist
+        .lottery
+        .as_ref()
+        .ok_or(Error::LotteryNotCommitted)?;
+    Ok(ViewLotteryResultResponse {
+        seed_hash: lottery.seed_hash,
+        seed: lottery.seed,
+        is_entrant: whitelist.entrants.contains(&params.addr),
+        is_winner: lottery.winners.contains(&params.addr),
+    })
+}
+
+/// View the allocation tier and cap of the inputted account in the token sale of the inputted
+/// project, based on its stake in the staking contract.
+///
+/// Caller: Any accounts / Any contracts
+/// Reject if:
+/// * The inputted project id has not been registered.
+/// * Invoking `view_stake` of the staking contract fails.
+#[receive(
+    contract = "overlay-projects",
+    name = "view_allocation",
+    parameter = "ViewAllocationParams",
+    return_value = "ViewAllocationResponse"
+)]
+fn contract_view_allocation<S: HasStateApi>(
+    ctx: &impl HasReceiveContext,
+    host: &impl HasHost<State<S>, StateApiType = S>,
+) -> ContractResult<ViewAllocationResponse> {
+    let mut params: ViewAllocationParams = ctx.parameter_cursor().get()?;
+    params.project_id = normalize_project_id(&params.project_id)?;
+    let state = host.state();
+    let project = state.project.get(&params.project_id);
+    ensure!(project.is_some(), Error::ProjectNotFound);
+    let tiers = project.unwrap().allocation_tiers.clone();
+
+    let func = EntrypointName::new_unchecked("view_stake");
+    let view_stake_params = ViewStakeParams { addr: params.addr };
+    let stake_state: StakeStateResponse = host
+        .invoke_contract_read_only(
+            &state.staking_contract_addr,
+            &view_stake_params,
+            func,
+            Amount::zero(),
+        )
+        .map_err(|_| Error::FailedInvokeStakingContractView)?
+        .ok_or(Error::FailedInvokeStakingContractView)?
+        .get()?;
+
+    let tier = tiers
+        .iter()
+        .rposition(|tier| tier.min_stake <= stake_state.amount);
+    Ok(ViewAllocationResponse {
+        stake: stake_state.amount,
+        tier: tier.map(|index| index as u32),
+        max_allocation: tier.map_or(0, |index| tiers[index].max_allocation),
+    })
+}
+
+/// implements Debug for State inside test functions.
+/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
+/// (e.g. when launched by `cargo concordium test`)
+#[concordium_cfg_test]
+impl<S: HasStateApi> Debug for State<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "admin: {:?}, staking_contract_addr: {:?}, user_contract_addr: {:?}, project_id_counter: {:?}, max_owners: {:?}, listeners: {:?}, reward_points: {:?}, curation_limits: {:?}, ",
+            self.admin, self.staking_contract_addr, self.user_contract_addr, self.project_id_counter, self.max_owners, self.listeners, self.reward_points, self.curation_limits,
+        )?;
+        for (project_id, project_state) in self.project.iter() {
+            write!(
+                f,
+                "project_id: {:?}, project_state: {:?}, ",
+                project_id, project_state
+            )?;
+        }
+        for (project_id, whitelist) in self.whitelist.iter() {
+            write!(
+                f,
+                "project_id: {:?}, whitelist: {:?}, ",
+                project_id, whitelist
+            )?;
+        }
+        for (account, nonce) in self.nonces.iter() {
+            write!(f, "account: {:?}, nonce: {:?}, ", account, nonce)?;
+        }
+        for (project_id, history) in self.metadata_history.iter() {
+            write!(
+                f,
+                "project_id: {:?}, metadata_history: {:?}, ",
+                project_id, history
+            )?;
+        }
+        for (tag, project_ids) in self.tags.iter() {
+            write!(f, "tag: {:?}, project_ids: {:?}, ", tag, project_ids)?;
+        }
+        for (account, reward) in self.rewards.iter() {
+            write!(f, "account: {:?}, reward: {:?}, ", account, reward)?;
+        }
+        for (account, stats) in self.curator_stats.iter() {
+            write!(f, "curator: {:?}, stats: {:?}, ", account, stats)?;
+        }
+        for (account, stats) in self.validator_stats.iter() {
+            write!(f, "validator: {:?}, stats: {:?}, ", account, stats)?;
+        }
+        for (account, quota) in self.curator_quotas.iter() {
+            write!(f, "curator: {:?}, quota: {:?}, ", account, quota)?;
+        }
+        for (project_id, project_state) in self.archive.iter() {
+            write!(
+                f,
+                "archived project_id: {:?}, project_state: {:?}, ",
+                project_id, project_state
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// implements PartialEq for `claim_eq` inside test functions.
+/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
+/// (e.g. when launched by `cargo concordium test`)
+#[concordium_cfg_test]
+impl<S: HasStateApi> PartialEq for State<S> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.admin != other.admin {
+            return false;
+        }
+        if self.staking_contract_addr != other.staking_contract_addr {
+            return false;
+        }
+        if self.user_contract_addr != other.user_contract_addr {
+            return false;
+        }
+        if self.project_id_counter != other.project_id_counter {
+            return false;
+        }
+        if self.max_owners != other.max_owners {
+            return false;
+        }
+        if self.listeners != other.listeners {
+            return false;
+        }
+        if self.reward_points != other.reward_points {
+            return false;
+        }
+        if self.curation_limits != other.curation_limits {
+            return false;
+        }
+        if self.project.iter().count() != other.project.iter().count() {
+            return false;
+        }
+        for (my_project_id, my_project_state) in self.project.iter() {
+            let other_project_state = other.project.get(&my_project_id);
+            if other_project_state.is_none() {
+                return false;
+            }
+            let other_project_state = other_project_state.unwrap();
+            if my_project_state.clone() != other_project_state.clone() {
+                return false;
+            }
+        }
+        if !state_map_eq(&self.whitelist, &other.whitelist) {
+            return false;
+        }
+        if !state_map_eq(&self.nonces, &other.nonces) {
+            return false;
+        }
+        if !state_map_eq(&self.metadata_history, &other.metadata_history) {
+            return false;
+        }
+        if !state_map_eq(&self.tags, &other.tags) {
+            return false;
+        }
+        if !state_map_eq(&self.archive, &other.archive) {
+            return false;
+        }
+        if !state_map_eq(&self.rewards, &other.rewards) {
+            return false;
+        }
+        if !state_map_eq(&self.curator_stats, &other.curator_stats) {
+            return false;
+        }
+        if !state_map_eq(&self.validator_stats, &other.validator_stats) {
+            return false;
+        }
+        if !state_map_eq(&self.curator_quotas, &other.curator_quotas) {
+            return false;
+        }
+        true
+    }
+}
+
+/// compares all entries of two state maps inside test functions.
+/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
+/// (e.g. when launched by `cargo concordium test`)
+#[concordium_cfg_test]
+fn state_map_eq<K, V, S>(a: &StateMap<K, V, S>, b: &StateMap<K, V, S>) -> bool
+where
+    K: Serialize,
+    V: Serial + DeserialWithState<S> + PartialEq,
+    S: HasStateApi,
+{
+    if a.iter().count() != b.iter().count() {
+        return false;
+    }
+    for (key, value) in a.iter() {
+        match b.get(&key) {
+            Some(other_value) if *value == *other_value => {},
+            _ => return false,
+        }
+    }
+    true
+}
+
+/// implements Debug for ProjectState inside test functions.
+/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
+/// (e.g. when launched by `cargo concordium test`)
+#[concordium_cfg_test]
+impl Debug for ProjectState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "project_uri: {:?}, owners: {:?}, pub_key: {:?}, token_addr: {:?}, seed_nft_addr: {:?}, sale_addr: {:?}, status: {:?}, token_generated_at: {:?}, listing_addr: {:?}, listed_at: {:?}, vesting_addr: {:?}, vesting_started_at: {:?}, vesting_schedule: {:?}, pending_vesting_schedule: {:?}, allocation_tiers: {:?}, signed_action_nonce: {:?}, pub_keys: {:?}, pending_project_uri: {:?}, profile: {:?}, pending_profile: {:?}, tags: {:?}, owner_threshold: {:?}, owner_proposal: {:?}, merged_into: {:?}, curated_by: {:?}, validated_by: {:?}",
+            self.project_uri, self.owners, self.pub_key, self.token_addr, self.seed_nft_addr, self.sale_addr, self.status, self.token_generated_at, self.listing_addr, self.listed_at, self.vesting_addr, self.vesting_started_at, self.vesting_schedule, self.pending_vesting_schedule, self.allocation_tiers, self.signed_action_nonce, self.pub_keys, self.pending_project_uri, self.profile, self.pending_profile, self.tags, self.owner_threshold, self.owner_proposal, self.merged_into, self.curated_by, self.validated_by
+        )
+    }
+}
+
+/// implements PartialEq for `claim_eq` inside test functions.
+/// this implementation will be build only when `concordium-std/wasm-test` feature is active.
+/// (e.g. when launched by `cargo concordium test`)
+#[concordium_cfg_test]
+impl PartialEq for ProjectState {
+    fn eq(&self, other: &Self) -> bool {
+        self.project_uri == other.project_uri
+            && self.owners == other.owners
+            && self.pub_key == other.pub_key
+            && self.token_addr == other.token_addr
+            && self.seed_nft_addr == other.seed_nft_addr
+            && self.sale_addr == other.sale_addr
+            && self.status == other.status
+            && self.token_generated_at == other.token_generated_at
+            && self.listing_addr == other.listing_addr
+            && self.listed_at == other.listed_at
+            && self.vesting_addr == other.vesting_addr
+            && self.vesting_started_at == other.vesting_started_at
+            && self.vesting_schedule == other.vesting_schedule
+            && self.pending_vesting_schedule == other.pending_vesting_schedule
+            && self.allocation_tiers == other.allocation_tiers
+            && self.signed_action_nonce == other.signed_action_nonce
+            && self.pub_keys == other.pub_keys
+            && self.pending_project_uri == other.pending_project_uri
+            && self.profile == other.profile
+            && self.pending_profile == other.pending_profile
+            && self.tags == other.tags
+            && self.owner_threshold == other.owner_threshold
+            && self.owner_proposal == other.owner_proposal
+            && self.merged_into == other.merged_into
+            && self.curated_by == other.curated_by
+            && self.validated_by == other.validated_by
+    }
+}
+
+#[concordium_cfg_test]
+#[allow(deprecated)]
+mod tests {
+    use super::*;
+    use test_infrastructure::*;
+
+    /// Build a Candidate project without any optional data. Tests spell out the fields they care
+    /// about and fill the rest with `..default_project()`.
+    fn default_project() -> ProjectState {
+        ProjectState {
+            project_uri: None,
+            owners: Vec::new(),
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Candidate,
+            token_generated_at: None,
+            listing_addr: None,
+            listed_at: None,
+            vesting_addr: None,
+            vesting_started_at: None,
+            vesting_schedule: None,
+            pending_vesting_schedule: None,
+            allocation_tiers: Vec::new(),
+            signed_action_nonce: 0,
+            pub_keys: Vec::new(),
+            pending_project_uri: None,
+            profile: ProjectProfile::default(),
+            pending_profile: None,
+            tags: Vec::new(),
+            owner_threshold: 1,
+            owner_proposal: None,
+            merged_into: None,
+            curated_by: None,
+            validated_by: None,
+        }
+    }
+
+    /// Build a state without any projects, as initialized by `init` with the contract addresses
+    /// used across tests. Tests spell out the fields they care about and fill the rest with
+    /// `..empty_state(&mut state_builder)`.
+    fn empty_state(state_builder: &mut TestStateBuilder) -> State<TestStateApi> {
+        State {
+            admin: AccountAddress([1; 32]),
+            staking_contract_addr: ContractAddress::new(1000, 0),
+            user_contract_addr: ContractAddress::new(1001, 0),
+            project: state_builder.new_map(),
+            whitelist: state_builder.new_map(),
+            nonces: state_builder.new_map(),
+            metadata_history: state_builder.new_map(),
+            tags: state_builder.new_map(),
+            project_id_counter: 0,
+            max_owners: DEFAULT_MAX_OWNERS,
+            archive: state_builder.new_map(),
+            listeners: Vec::new(),
+            rewards: state_builder.new_map(),
+            reward_points: RewardPoints {
+                whitelist: DEFAULT_WHITELIST_REWARD_POINTS,
+                sale_closed: DEFAULT_SALE_CLOSED_REWARD_POINTS,
+            },
+            curator_stats: state_builder.new_map(),
+            validator_stats: state_builder.new_map(),
+            curation_limits: CurationLimits {
+                max_open_candidates: DEFAULT_MAX_OPEN_CANDIDATES,
+                max_curations_per_window: DEFAULT_MAX_CURATIONS_PER_WINDOW,
+                window: Duration::from_millis(DEFAULT_CURATION_WINDOW_MILLIS),
+            },
+            curator_quotas: state_builder.new_map(),
+        }
+    }
+
+    /// Mock of overlay-users.update_project_status which traps unless it is notified of the
+    /// inputted status of the inputted project.
+    fn mock_update_project_status(
+        project_id: ProjectId,
+        status: ProjectStatus,
+    ) -> MockFn<State<TestStateApi>> {
+        MockFn::new_v1(move |parameter, _amount, _balance, _state| {
+            let params: UpdateProjectStatusParams =
+                from_bytes(parameter.as_ref()).map_err(|_| CallContractError::Trap)?;
+            if params.project_id != project_id || params.status != status {
+                return Err(CallContractError::Trap);
+            }
+            Ok((false, ()))
+        })
+    }
+
+    /// Mock of a listener contract which traps unless it is invoked with the inputted
+    /// notification.
+    fn mock_listener(expected: ProjectNotification) -> MockFn<State<TestStateApi>> {
+        MockFn::new_v1(move |parameter, _amount, _balance, _state| {
+            let notification: ProjectNotification =
+                from_bytes(parameter.as_ref()).map_err(|_| CallContractError::Trap)?;
+            if notification != expected {
+                return Err(CallContractError::Trap);
+            }
+            Ok((false, ()))
+        })
+    }
+
+    #[concordium_test]
+    /// Test that init succeeds.
+    fn test_init() {
+        // invoker will be an admin
+        let invoker = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+
+        let mut ctx = TestInitContext::empty();
+        let mut state_builder = TestStateBuilder::new();
+        ctx.set_init_origin(invoker);
+
+        // prepare for expected state after init
+        let expected_state = State {
+            admin: invoker,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+
+        // create params
+        let params = InitParams {
+            staking_contract_addr,
+            user_contract_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        // execute init
+        let result = contract_init(&ctx, &mut state_builder);
+        // check init result
+        claim!(result.is_ok());
+        let actual_state = result.unwrap();
+        claim_eq!(
+            actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.update_contract_state.
+    fn test_contract_update_contract_state_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let invoker = AccountAddress([7; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let next_staking_contract_addr = ContractAddress::new(2000, 0);
+        let next_user_contract_addr = ContractAddress::new(2001, 0);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(invoker);
+
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = UpdateContractStateParams {
+            staking_contract_addr: next_staking_contract_addr,
+            user_contract_addr: next_user_contract_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_update_contract_state(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.update_contract_state successfully update
+    /// staking_contract_addr and user_contract_addr.
+    fn test_contract_update_contract_state() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let next_staking_contract_addr = ContractAddress::new(2000, 0);
+        let next_user_contract_addr = ContractAddress::new(2001, 0);
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let expected_state = State {
+            admin,
+            staking_contract_addr: next_staking_contract_addr,
+            user_contract_addr: next_user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = UpdateContractStateParams {
+            staking_contract_addr: next_staking_contract_addr,
+            user_contract_addr: next_user_contract_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_update_contract_state(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_update_contract_state: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.transfer_admin.
+    fn test_contract_transfer_admin_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let invoker = AccountAddress([8; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(invoker);
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = TransferAdminParams { admin: invoker };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_transfer_admin(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.transfer_admin successfully update admin.
+    fn test_contract_transfer_admin() {
+        let admin = AccountAddress([1; 32]);
+        let admin_to_be_set = AccountAddress([2u8; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let expected_state = State {
+            admin: admin_to_be_set,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = TransferAdminParams {
+            admin: admin_to_be_set,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_transfer_admin(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_transfer_admin: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.apply_curate_project.
+    fn test_contract_apply_curate_project_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let non_admin = AccountAddress([7u8; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "somethingdangerous".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(non_admin);
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ApplyCurateProjectParams {
+            project_id: Some(project_id),
+            project_uri,
+            owners: vec![project_owner1, project_owner2],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_apply_curate_project(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.apply_curate_project successfully update project.
+    fn test_contract_apply_curate_project() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjk".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([5; 32]);
+        let project_owner2 = AccountAddress([6; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_metadata_history = state_builder.new_map();
+        expected_metadata_history.insert(
+            project_id.clone(),
+            vec![MetadataRecord {
+                uri: project_uri.clone(),
+                timestamp: Timestamp::from_timestamp_millis(100),
+                changed_by: admin,
+            }],
+        );
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                owner_threshold: 2,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            metadata_history: expected_metadata_history,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            mock_update_project_status(project_id.clone(), ProjectStatus::Candidate),
+        );
+
+        let params = ApplyCurateProjectParams {
+            project_id: Some(project_id),
+            project_uri,
+            owners: vec![project_owner1, project_owner2],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_apply_curate_project(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_apply_curate_project: Results in rejection."
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.curate_project.
+    fn test_contract_curate_project_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "somethingdangerous".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.view_user mock to return non-curator user.
+        // this leads to InvalidCaller error.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+
+        let params = CurateProjectParams {
+            project_id: Some(project_id),
+            project_uri,
+            owners: vec![project_owner1, project_owner2],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        ctx.set_sender(Address::Account(project_owner1));
+        let _ = host.with_rollback(|host| contract_curate_project(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.curate_project successfully invoke overlay-users.curate function.
+    fn test_contract_curate_project() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjk".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([5; 32]);
+        let project_owner2 = AccountAddress([6; 32]);
+        let curator_address = AccountAddress([3; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_metadata_history = state_builder.new_map();
+        expected_metadata_history.insert(
+            project_id.clone(),
+            vec![MetadataRecord {
+                uri: project_uri.clone(),
+                timestamp: Timestamp::from_timestamp_millis(100),
+                changed_by: curator_address,
+            }],
+        );
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                owner_threshold: 2,
+                curated_by: Some(curator_address),
+                ..default_project()
+            },
+        );
+        let mut expected_curator_stats = state_builder.new_map();
+        expected_curator_stats.insert(
+            curator_address,
+            ProjectStats {
+                projects: 1,
+                whitelisted: 0,
+                sold_out: 0,
+                rejected: 0,
+            },
+        );
+        let mut expected_curator_quotas = state_builder.new_map();
+        expected_curator_quotas.insert(
+            curator_address,
+            CuratorQuota {
+                open_candidates: 1,
+                window_start: Timestamp::from_timestamp_millis(100),
+                window_curations: 1,
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            metadata_history: expected_metadata_history,
+            curator_stats: expected_curator_stats,
+            curator_quotas: expected_curator_quotas,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.view_user mock to return curator user so that the curate func would
+        // be called by this func.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+        // set up overlay-users.curate.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("curate".to_string()),
+            MockFn::returning_ok(()),
+        );
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            mock_update_project_status(project_id.clone(), ProjectStatus::Candidate),
+        );
+
+        let params = CurateProjectParams {
+            project_id: Some(project_id),
+            project_uri,
+            owners: vec![project_owner1, project_owner2],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        ctx.set_sender(Address::Account(curator_address));
+        let result: ContractResult<ProjectId> = contract_curate_project(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_curate_project: Results in rejection."
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.curate_project_admin.
+    fn test_contract_curate_project_admin_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "somethingdangerous".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.view_user mock to return non-curator user.
+        // this leads to InvalidCaller error.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+
+        let params = CurateProjectAdminParams {
+            curator: project_owner1,
+            project_id: Some(project_id),
+            project_uri,
+            owners: vec![project_owner1, project_owner2],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_curate_project_admin(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.curate_project_admin successfully finish its process.
+    fn test_contract_curate_project_admin() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjk".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([5; 32]);
+        let project_owner2 = AccountAddress([6; 32]);
+        let curator = AccountAddress([3; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_metadata_history = state_builder.new_map();
+        expected_metadata_history.insert(
+            project_id.clone(),
+            vec![MetadataRecord {
+                uri: project_uri.clone(),
+                timestamp: Timestamp::from_timestamp_millis(100),
+                changed_by: admin,
+            }],
+        );
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                owner_threshold: 2,
+                curated_by: Some(curator),
+                ..default_project()
+            },
+        );
+        let mut expected_curator_stats = state_builder.new_map();
+        expected_curator_stats.insert(
+            curator,
+            ProjectStats {
+                projects: 1,
+                whitelisted: 0,
+                sold_out: 0,
+                rejected: 0,
+            },
+        );
+        let mut expected_curator_quotas = state_builder.new_map();
+        expected_curator_quotas.insert(
+            curator,
+            CuratorQuota {
+                open_candidates: 1,
+                window_start: Timestamp::from_timestamp_millis(100),
+                window_curations: 1,
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            metadata_history: expected_metadata_history,
+            curator_stats: expected_curator_stats,
+            curator_quotas: expected_curator_quotas,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.view_user mock to return curator user so that the curate func would
+        // be called by this func.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+        // set up overlay-users.curate.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("curate".to_string()),
+            MockFn::returning_ok(()),
+        );
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            mock_update_project_status(project_id.clone(), ProjectStatus::Candidate),
+        );
+
+        let params = CurateProjectAdminParams {
+            curator,
+            project_id: Some(project_id),
+            project_uri,
+            owners: vec![project_owner1, project_owner2],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result: ContractResult<ProjectId> = contract_curate_project_admin(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_curate_project: Results in rejection."
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.curate_project_admin rejects without registering the project when
+    /// invoking overlay-users.update_project_status fails.
+    fn test_contract_curate_project_admin_with_failing_user_contract() {
+        let admin = AccountAddress([1; 32]);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjk".into();
+        let curator = AccountAddress([3; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr: ContractAddress::new(1000, 0),
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let expected_state = State {
+            admin,
+            staking_contract_addr: ContractAddress::new(1000, 0),
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("curate".to_string()),
+            MockFn::returning_ok(()),
+        );
+        // set up overlay-users.update_project_status to fail.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            MockFn::returning_err::<()>(CallContractError::Trap),
+        );
+
+        let params = CurateProjectAdminParams {
+            curator,
+            project_id: Some(project_id),
+            project_uri: ProjectUri {
+                url: "https://overlay.global/".into(),
+                hash: Some([0; 32]),
+            },
+            owners: vec![AccountAddress([5; 32])],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_curate_project_admin(&ctx, host));
+        claim_eq!(result, Err(Error::FailedInvokeUserContract));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.contract_validate_project.
+    fn test_contract_validate_project_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let non_validator = AccountAddress([9; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.view_user mock to return non-curator user.
+        // this leads to InvalidCaller error.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+
+        let params = ValidateProjectParams {
+            project_id,
+            owners: vec![project_owner1, project_owner2],
+            token_addr: None,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        ctx.set_sender(Address::Account(non_validator));
+        let _ = host.with_rollback(|host| contract_validate_project(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.validate_project successfully invoke overlay-users.validate
+    /// function.
+    fn test_contract_validate_project() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjk".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([5; 32]);
+        let project_owner2 = AccountAddress([6; 32]);
+        let validator = AccountAddress([3; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                owner_threshold: 2,
+                status: ProjectStatus::Whitelist,
+                validated_by: Some(validator),
+                ..default_project()
+            },
+        );
+        let mut expected_rewards = state_builder.new_map();
+        expected_rewards.insert(
+            validator,
+            RewardState {
+                pending: DEFAULT_WHITELIST_REWARD_POINTS,
+                claimed: 0,
+            },
+        );
+        let mut expected_validator_stats = state_builder.new_map();
+        expected_validator_stats.insert(
+            validator,
+            ProjectStats {
+                projects: 1,
+                whitelisted: 1,
+                sold_out: 0,
+                rejected: 0,
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            rewards: expected_rewards,
+            validator_stats: expected_validator_stats,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.view_user mock to return curator user so that the curate func would
+        // be called by this func.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            mock_update_project_status(project_id.clone(), ProjectStatus::Whitelist),
+        );
+        // set up overlay-users.curate.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("validate".to_string()),
+            MockFn::returning_ok(()),
+        );
+
+        let params = ValidateProjectParams {
+            project_id,
+            owners: vec![project_owner1, project_owner2],
+            token_addr: None,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        ctx.set_sender(Address::Account(validator));
+        let result: ContractResult<()> = contract_validate_project(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_curate_project: Results in rejection."
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.contract_validate_project_admin.
+    fn test_contract_validate_project_admin_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let non_validator = AccountAddress([9; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.view_user mock to return non-curator user.
+        // this leads to InvalidCaller error.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+
+        let params = ValidateProjectAdminParams {
+            validator: non_validator,
+            project_id,
+            owners: vec![project_owner1, project_owner2],
+            token_addr: None,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_validate_project_admin(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.validate_project_admin successfully finish its process.
+    fn test_contract_validate_project_admin() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjk".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([5; 32]);
+        let project_owner2 = AccountAddress([6; 32]);
+        let validator = AccountAddress([3; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                owner_threshold: 2,
+                status: ProjectStatus::Whitelist,
+                validated_by: Some(validator),
+                ..default_project()
+            },
+        );
+        let mut expected_rewards = state_builder.new_map();
+        expected_rewards.insert(
+            validator,
+            RewardState {
+                pending: DEFAULT_WHITELIST_REWARD_POINTS,
+                claimed: 0,
+            },
+        );
+        let mut expected_validator_stats = state_builder.new_map();
+        expected_validator_stats.insert(
+            validator,
+            ProjectStats {
+                projects: 1,
+                whitelisted: 1,
+                sold_out: 0,
+                rejected: 0,
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            rewards: expected_rewards,
+            validator_stats: expected_validator_stats,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.view_user mock to return curator user so that the curate func would
+        // be called by this func.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            mock_update_project_status(project_id.clone(), ProjectStatus::Whitelist),
+        );
+        // set up overlay-users.validate.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("validate".to_string()),
+            MockFn::returning_ok(()),
+        );
+
+        let params = ValidateProjectAdminParams {
+            validator,
+            project_id,
+            owners: vec![project_owner1, project_owner2],
+            token_addr: None,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result: ContractResult<()> = contract_validate_project_admin(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_curate_project: Results in rejection."
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.validate_project rejects the curator of the project, so that one
+    /// account can not earn both the curator and the validator rewards.
+    fn test_contract_validate_project_by_curator() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjk".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([5; 32]);
+        let curator = AccountAddress([3; 32]);
+        let project_state = ProjectState {
+            project_uri: Some(project_uri),
+            owners: vec![project_owner1],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Candidate,
+            curated_by: Some(curator),
+            ..default_project()
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(curator);
+        ctx.set_sender(Address::Account(curator));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.view_user mock to return a user who is both curator and validator.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: true,
+                is_validator: true,
+                curated_projects: vec![project_id.clone()],
+                validated_projects: Vec::new(),
+            }),
+        );
+
+        let params = ValidateProjectParams {
+            project_id,
+            owners: Vec::new(),
+            token_addr: None,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_validate_project(&ctx, host));
+        claim_eq!(result, Err(Error::ValidatorIsCurator));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.add_token_addr.
+    fn test_contract_add_token_addr_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let token_addr = ContractAddress::new(1002, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner1);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = AddTokenAddrParams {
+            project_id,
+            token_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_add_token_addr(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.add_token_addr successfully update project's token address.
+    fn test_contract_add_token_addr() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let token_addr = ContractAddress::new(1002, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner1);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: Some(token_addr),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = AddTokenAddrParams {
+            project_id,
+            token_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_add_token_addr(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.add_pub_key.
+    fn test_contract_add_pub_key_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let pub_key = PublicKeyEd25519([9; 32]);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = AddPubKeyParams {
+            project_id,
+            pub_key,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_add_pub_key(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.add_pub_key successfully update project's pub_key
+    fn test_contract_add_pub_key() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let pub_key = PublicKeyEd25519([9; 32]);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: Some(pub_key),
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                pub_keys: vec![PubKeyRecord {
+                    pub_key,
+                    activated_at: Timestamp::from_timestamp_millis(10),
+                    revoked_at: None,
+                }],
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = AddPubKeyParams {
+            project_id,
+            pub_key,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_add_pub_key(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.update_owners.
+    fn test_contract_update_owners_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let new_project_owner1 = AccountAddress([9; 32]);
+        let new_project_owner2 = AccountAddress([10; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = UpdateOwnersParams {
+            project_id,
+            owners: vec![new_project_owner1, new_project_owner2],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_update_owners(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.update_owners successfully update project's pub_key
+    fn test_contract_update_owners() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let new_project_owner1 = AccountAddress([9; 32]);
+        let new_project_owner2 = AccountAddress([10; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![new_project_owner1, new_project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                owner_threshold: 2,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = UpdateOwnersParams {
+            project_id,
+            owners: vec![new_project_owner1, new_project_owner2],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_update_owners(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.add_seed_sale.
+    fn test_contract_add_seed_sale_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let seed_nft_addr = ContractAddress::new(1002, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = AddSeedSaleParams {
+            project_id,
+            seed_nft_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_add_seed_sale(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.add_seed_sale successfully update project's seed NFT address.
+    fn test_contract_add_seed_sale() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let seed_nft_addr = ContractAddress::new(1002, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: Some(seed_nft_addr),
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = AddSeedSaleParams {
+            project_id,
+            seed_nft_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_add_seed_sale(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.start_sale.
+    fn test_contract_start_sale_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = StartSaleParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_start_sale(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.start_sale successfully update project's status as "on sale".
+    fn test_contract_start_sale() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::OnSale,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            mock_update_project_status(project_id.clone(), ProjectStatus::OnSale),
+        );
+
+        let params = StartSaleParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_start_sale(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.start_sale rejects and keeps the project status when invoking
+    /// overlay-users.update_project_status fails.
+    fn test_contract_start_sale_with_failing_user_contract() {
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_state = ProjectState {
+            project_uri: None,
+            owners: vec![AccountAddress([7; 32])],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Whitelist,
+            ..default_project()
+        };
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let initial_state = State {
+            admin: AccountAddress([1; 32]),
+            staking_contract_addr: ContractAddress::new(1000, 0),
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let expected_state = State {
+            admin: AccountAddress([1; 32]),
+            staking_contract_addr: ContractAddress::new(1000, 0),
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.update_project_status to fail.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            MockFn::returning_err::<()>(CallContractError::Trap),
+        );
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(host.state().admin);
+        let params = StartSaleParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_start_sale(&ctx, host));
+        claim_eq!(result, Err(Error::FailedInvokeUserContract));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.close_sale.
+    fn test_contract_close_sale_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = CloseSaleParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_close_sale(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.close_sale successfully update project's status as "sale closed".
+    fn test_contract_close_sale() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::OnSale,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::SaleClosed,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            mock_update_project_status(project_id.clone(), ProjectStatus::SaleClosed),
+        );
+
+        let params = CloseSaleParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_close_sale(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.generate_token.
+    fn test_contract_generate_token_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let token_addr = ContractAddress::new(2000, 0);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner1);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::OnSale,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::OnSale,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = GenerateTokenParams {
+            project_id,
+            token_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_generate_token(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.generate_token rejects a token address which differs from the one
+    /// already registered for the project.
+    fn test_contract_generate_token_token_addr_conflict() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let token_addr = ContractAddress::new(2000, 0);
+        let registered_token_addr = ContractAddress::new(2001, 0);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: Some(registered_token_addr),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::SaleClosed,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: Some(registered_token_addr),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::SaleClosed,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = GenerateTokenParams {
+            project_id,
+            token_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_generate_token(&ctx, host));
+        claim_eq!(result, Err(Error::TokenAddrConflict));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.generate_token rejects an owner of the project, so that owners
+    /// cannot end the refundable period of a closed sale.
+    fn test_contract_generate_token_by_owner() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let token_addr = ContractAddress::new(2000, 0);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner1);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(10));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::SaleClosed,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::SaleClosed,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = GenerateTokenParams {
+            project_id,
+            token_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_generate_token(&ctx, host));
+        claim_eq!(result, Err(Error::InvalidCaller));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.generate_token successfully update project's status as
+    /// "token generated".
+    fn test_contract_generate_token() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let token_addr = ContractAddress::new(2000, 0);
+        let now = Timestamp::from_timestamp_millis(10);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(now);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::SaleClosed,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: Some(token_addr),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::TokenGenerated,
+                token_generated_at: Some(now),
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            mock_update_project_status(project_id.clone(), ProjectStatus::TokenGenerated),
+        );
+
+        let params = GenerateTokenParams {
+            project_id,
+            token_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_generate_token(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_generate_token: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.list_token.
+    fn test_contract_list_token_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let invoker = AccountAddress([9; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let token_addr = ContractAddress::new(2000, 0);
+        let listing_addr = ContractAddress::new(2001, 0);
+        let token_generated_at = Timestamp::from_timestamp_millis(10);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(invoker);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(20));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: Some(token_addr),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::TokenGenerated,
+                token_generated_at: Some(token_generated_at),
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: Some(token_addr),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::TokenGenerated,
+                token_generated_at: Some(token_generated_at),
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ListTokenParams {
+            project_id,
+            listing_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_list_token(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.list_token successfully update project's status as "listed".
+    fn test_contract_list_token() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let token_addr = ContractAddress::new(2000, 0);
+        let listing_addr = ContractAddress::new(2001, 0);
+        let token_generated_at = Timestamp::from_timestamp_millis(10);
+        let now = Timestamp::from_timestamp_millis(20);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(now);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: Some(token_addr),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::TokenGenerated,
+                token_generated_at: Some(token_generated_at),
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: Some(token_addr),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Listed,
+                token_generated_at: Some(token_generated_at),
+                listing_addr: Some(listing_addr),
+                listed_at: Some(now),
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            mock_update_project_status(project_id.clone(), ProjectStatus::Listed),
+        );
+
+        let params = ListTokenParams {
+            project_id,
+            listing_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_list_token(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_list_token: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.start_vesting.
+    fn test_contract_start_vesting_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let token_addr = ContractAddress::new(2000, 0);
+        let vesting_addr = ContractAddress::new(2002, 0);
+        let token_generated_at = Timestamp::from_timestamp_millis(10);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(20));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: Some(token_addr),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::TokenGenerated,
+                token_generated_at: Some(token_generated_at),
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: Some(token_addr),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::TokenGenerated,
+                token_generated_at: Some(token_generated_at),
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = StartVestingParams {
+            project_id,
+            vesting_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let _ = host.with_rollback(|host| contract_start_vesting(&ctx, host));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.start_vesting successfully update project's status as "vesting".
+    fn test_contract_start_vesting() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let token_addr = ContractAddress::new(2000, 0);
+        let listing_addr = ContractAddress::new(2001, 0);
+        let vesting_addr = ContractAddress::new(2002, 0);
+        let token_generated_at = Timestamp::from_timestamp_millis(10);
+        let listed_at = Timestamp::from_timestamp_millis(20);
+        let now = Timestamp::from_timestamp_millis(30);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner2);
+        ctx.set_metadata_slot_time(now);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: Some(token_addr),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Listed,
+                token_generated_at: Some(token_generated_at),
+                listing_addr: Some(listing_addr),
+                listed_at: Some(listed_at),
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: Some(token_addr),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Vesting,
+                token_generated_at: Some(token_generated_at),
+                listing_addr: Some(listing_addr),
+                listed_at: Some(listed_at),
+                vesting_addr: Some(vesting_addr),
+                vesting_started_at: Some(now),
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            mock_update_project_status(project_id.clone(), ProjectStatus::Vesting),
+        );
+
+        let params = StartVestingParams {
+            project_id,
+            vesting_addr,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_start_vesting(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_start_vesting: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.propose_vesting_schedule.
+    fn test_contract_propose_vesting_schedule_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let vesting_contract_addr = ContractAddress::new(2002, 0);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner1);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // tranches do not unlock 100% in total.
+        let params = ProposeVestingScheduleParams {
+            project_id,
+            schedule: VestingSchedule {
+                cliff: Timestamp::from_timestamp_millis(100),
+                tranches: vec![
+                    (Timestamp::from_timestamp_millis(100), 2500),
+                    (Timestamp::from_timestamp_millis(200), 2500),
+                ],
+                vesting_contract_addr,
+            },
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_propose_vesting_schedule(&ctx, host));
+        claim_eq!(result, Err(Error::InvalidVestingSchedule));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.propose_vesting_schedule successfully update project's pending
+    /// vesting schedule.
+    fn test_contract_propose_vesting_schedule() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let schedule = VestingSchedule {
+            cliff: Timestamp::from_timestamp_millis(100),
+            tranches: vec![
+                (Timestamp::from_timestamp_millis(100), 2500),
+                (Timestamp::from_timestamp_millis(200), 7500),
+            ],
+            vesting_contract_addr: ContractAddress::new(2002, 0),
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner1);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                pending_vesting_schedule: Some(schedule.clone()),
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ProposeVestingScheduleParams {
+            project_id,
+            schedule,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_propose_vesting_schedule(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_propose_vesting_schedule: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.approve_vesting_schedule.
+    fn test_contract_approve_vesting_schedule_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let schedule = VestingSchedule {
+            cliff: Timestamp::from_timestamp_millis(100),
+            tranches: vec![(Timestamp::from_timestamp_millis(100), 10000)],
+            vesting_contract_addr: ContractAddress::new(2002, 0),
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::OnSale,
+                pending_vesting_schedule: Some(schedule.clone()),
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::OnSale,
+                pending_vesting_schedule: Some(schedule),
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ApproveVestingScheduleParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_approve_vesting_schedule(&ctx, host));
+        claim_eq!(result, Err(Error::InvalidStatus));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.approve_vesting_schedule successfully apply project's pending
+    /// vesting schedule.
+    fn test_contract_approve_vesting_schedule() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let schedule = VestingSchedule {
+            cliff: Timestamp::from_timestamp_millis(100),
+            tranches: vec![(Timestamp::from_timestamp_millis(100), 10000)],
+            vesting_contract_addr: ContractAddress::new(2002, 0),
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                pending_vesting_schedule: Some(schedule.clone()),
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                vesting_schedule: Some(schedule),
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ApproveVestingScheduleParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_approve_vesting_schedule(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_approve_vesting_schedule: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.view_unlocked_percentage returns unlocked basis points of the
+    /// approved vesting schedule.
+    fn test_contract_view_unlocked_percentage() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let schedule = VestingSchedule {
+            cliff: Timestamp::from_timestamp_millis(100),
+            tranches: vec![
+                (Timestamp::from_timestamp_millis(150), 2500),
+                (Timestamp::from_timestamp_millis(200), 7500),
+            ],
+            vesting_contract_addr: ContractAddress::new(2002, 0),
+        };
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri),
+                owners: vec![project_owner1],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::OnSale,
+                vesting_schedule: Some(schedule),
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let host = TestHost::new(initial_state, state_builder);
+
+        for (time, expected) in [(50, 0), (150, 2500), (199, 2500), (200, 10000)] {
+            let mut ctx = TestReceiveContext::empty();
+            let params = ViewUnlockedPercentageParams {
+                project_id: project_id.clone(),
+                time: Timestamp::from_timestamp_millis(time),
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+            let result = contract_view_unlocked_percentage(&ctx, &host);
+            claim_eq!(result, Ok(expected));
+        }
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.start_refund.
+    fn test_contract_start_refund_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::OnSale,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::OnSale,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = StartRefundParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_start_refund(&ctx, host));
+        claim_eq!(result, Err(Error::SaleNotFound));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.start_refund successfully update project's status as
+    /// "refunding" and invoke open_refund function of the sale contract.
+    fn test_contract_start_refund() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let sale_addr = ContractAddress::new(2003, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: Some(sale_addr),
+                status: ProjectStatus::SaleClosed,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: Some(sale_addr),
+                status: ProjectStatus::Refunding,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            mock_update_project_status(project_id.clone(), ProjectStatus::Refunding),
+        );
+
+        // set up sale contract's open_refund.
+        host.setup_mock_entrypoint(
+            sale_addr,
+            OwnedEntrypointName::new_unchecked("open_refund".to_string()),
+            MockFn::returning_ok(()),
+        );
+
+        let params = StartRefundParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_start_refund(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_start_refund: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.complete_refund.
+    fn test_contract_complete_refund_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let sale_addr = ContractAddress::new(2003, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Account(admin));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: Some(sale_addr),
+                status: ProjectStatus::Refunding,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: Some(sale_addr),
+                status: ProjectStatus::Refunding,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = CompleteRefundParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_complete_refund(&ctx, host));
+        claim_eq!(result, Err(Error::InvalidCaller));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.complete_refund successfully update project's status as
+    /// "cancelled".
+    fn test_contract_complete_refund() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let sale_addr = ContractAddress::new(2003, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Contract(sale_addr));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: Some(sale_addr),
+                status: ProjectStatus::Refunding,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: Some(sale_addr),
+                status: ProjectStatus::Cancelled,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            mock_update_project_status(project_id.clone(), ProjectStatus::Cancelled),
+        );
+
+        let params = CompleteRefundParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_complete_refund(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_complete_refund: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.set_whitelist_cap.
+    fn test_contract_set_whitelist_cap_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_state = ProjectState {
+            project_uri: None,
+            owners: vec![AccountAddress([7; 32])],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Whitelist,
+            ..default_project()
+        };
+        let params_bytes: Vec<Vec<u8>> = [0, MAX_WHITELIST_ENTRANTS + 1]
+            .into_iter()
+            .map(|max_entrants| {
+                to_bytes(&SetWhitelistCapParams {
+                    project_id: project_id.clone(),
+                    max_entrants,
+                })
+            })
+            .collect();
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let initial_state = State {
+            admin: AccountAddress([1; 32]),
+            staking_contract_addr: ContractAddress::new(1000, 0),
+            user_contract_addr: ContractAddress::new(1001, 0),
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let expected_state = State {
+            admin: AccountAddress([1; 32]),
+            staking_contract_addr: ContractAddress::new(1000, 0),
+            user_contract_addr: ContractAddress::new(1001, 0),
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        for params_byte in &params_bytes {
+            ctx.set_parameter(params_byte);
+            let result = host.with_rollback(|host| contract_set_whitelist_cap(&ctx, host));
+            claim_eq!(result, Err(Error::InvalidWhitelistCap));
+        }
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.set_whitelist_cap successfully update maximum number of entrants.
+    fn test_contract_set_whitelist_cap() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_state = ProjectState {
+            project_uri: Some(project_uri),
+            owners: vec![project_owner1],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Whitelist,
+            ..default_project()
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let mut expected_whitelist = state_builder.new_map();
+        expected_whitelist.insert(
+            project_id.clone(),
+            WhitelistState {
+                max_entrants: 100,
+                entrants: Vec::new(),
+                lottery: None,
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            whitelist: expected_whitelist,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = SetWhitelistCapParams {
+            project_id,
+            max_entrants: 100,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_set_whitelist_cap(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_set_whitelist_cap: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.register_whitelist.
+    fn test_contract_register_whitelist_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let entrant1 = AccountAddress([10; 32]);
+        let entrant2 = AccountAddress([11; 32]);
+        let project_state = ProjectState {
+            project_uri: Some(project_uri),
+            owners: vec![project_owner1],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Whitelist,
+            ..default_project()
+        };
+        let whitelist_state = WhitelistState {
+            max_entrants: 1,
+            entrants: vec![entrant1],
+            lottery: None,
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Account(entrant2));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let mut initial_whitelist = state_builder.new_map();
+        initial_whitelist.insert(project_id.clone(), whitelist_state.clone());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            whitelist: initial_whitelist,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let mut expected_whitelist = state_builder.new_map();
+        expected_whitelist.insert(project_id.clone(), whitelist_state);
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            whitelist: expected_whitelist,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+
+        let params = RegisterWhitelistParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_register_whitelist(&ctx, host));
+        claim_eq!(result, Err(Error::WhitelistFull));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.register_whitelist successfully add the caller to entrants.
+    fn test_contract_register_whitelist() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let entrant1 = AccountAddress([10; 32]);
+        let entrant2 = AccountAddress([11; 32]);
+        let project_state = ProjectState {
+            project_uri: Some(project_uri),
+            owners: vec![project_owner1],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Whitelist,
+            ..default_project()
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_sender(Address::Account(entrant2));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let mut initial_whitelist = state_builder.new_map();
+        initial_whitelist.insert(
+            project_id.clone(),
+            WhitelistState {
+                max_entrants: 2,
+                entrants: vec![entrant1],
+                lottery: None,
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            whitelist: initial_whitelist,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let mut expected_whitelist = state_builder.new_map();
+        expected_whitelist.insert(
+            project_id.clone(),
+            WhitelistState {
+                max_entrants: 2,
+                entrants: vec![entrant1, entrant2],
+                lottery: None,
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            whitelist: expected_whitelist,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: false,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+
+        let params = RegisterWhitelistParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_register_whitelist(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_register_whitelist: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.view_whitelist returns entrant count and membership.
+    fn test_contract_view_whitelist() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let entrant1 = AccountAddress([10; 32]);
+        let entrant2 = AccountAddress([11; 32]);
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri),
+                owners: vec![project_owner1],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let mut initial_whitelist = state_builder.new_map();
+        initial_whitelist.insert(
+            project_id.clone(),
+            WhitelistState {
+                max_entrants: MAX_WHITELIST_ENTRANTS,
+                entrants: vec![entrant1],
+                lottery: None,
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            whitelist: initial_whitelist,
+            ..empty_state(&mut state_builder)
+        };
+        let host = TestHost::new(initial_state, state_builder);
+
+        for (addr, is_registered) in [(entrant1, true), (entrant2, false)] {
+            let mut ctx = TestReceiveContext::empty();
+            let params = ViewWhitelistParams {
+                project_id: project_id.clone(),
+                addr,
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+            let result = contract_view_whitelist(&ctx, &host);
+            claim_eq!(
+                result,
+                Ok(ViewWhitelistResponse {
+                    max_entrants: MAX_WHITELIST_ENTRANTS,
+                    entrant_count: 1,
+                    is_registered,
+                })
+            );
+        }
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.commit_lottery.
+    fn test_contract_commit_lottery_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_state = ProjectState {
+            project_uri: Some(project_uri),
+            owners: vec![project_owner1],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Whitelist,
+            ..default_project()
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = CommitLotteryParams {
+            project_id,
+            seed_hash: [7; 32],
+            winner_count: 2,
+            registration_deadline: Timestamp::from_timestamp_millis(100),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_commit_lottery(&ctx, host));
+        claim_eq!(result, Err(Error::RegistrationClosed));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.commit_lottery rejects a lottery without winners.
+    fn test_contract_commit_lottery_zero_winner_count() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_state = ProjectState {
+            project_uri: Some(project_uri),
+            owners: vec![project_owner1],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Whitelist,
+            ..default_project()
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(50));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = CommitLotteryParams {
+            project_id,
+            seed_hash: [7; 32],
+            winner_count: 0,
+            registration_deadline: Timestamp::from_timestamp_millis(100),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_commit_lottery(&ctx, host));
+        claim_eq!(result, Err(Error::InvalidWinnerCount));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.commit_lottery successfully store the committed seed hash.
+    fn test_contract_commit_lottery() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let entrant1 = AccountAddress([10; 32]);
+        let project_state = ProjectState {
+            project_uri: Some(project_uri),
+            owners: vec![project_owner1],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Whitelist,
+            ..default_project()
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(50));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let mut initial_whitelist = state_builder.new_map();
+        initial_whitelist.insert(
+            project_id.clone(),
+            WhitelistState {
+                max_entrants: MAX_WHITELIST_ENTRANTS,
+                entrants: vec![entrant1],
+                lottery: None,
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            whitelist: initial_whitelist,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let mut expected_whitelist = state_builder.new_map();
+        expected_whitelist.insert(
+            project_id.clone(),
+            WhitelistState {
+                max_entrants: MAX_WHITELIST_ENTRANTS,
+                entrants: vec![entrant1],
+                lottery: Some(Lottery {
+                    seed_hash: [7; 32],
+                    winner_count: 2,
+                    registration_deadline: Timestamp::from_timestamp_millis(100),
+                    seed: None,
+                    winners: Vec::new(),
+                }),
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            whitelist: expected_whitelist,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = CommitLotteryParams {
+            project_id,
+            seed_hash: [7; 32],
+            winner_count: 2,
+            registration_deadline: Timestamp::from_timestamp_millis(100),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_commit_lottery(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_commit_lottery: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.reveal_lottery.
+    fn test_contract_reveal_lottery_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let entrant1 = AccountAddress([10; 32]);
+        let project_state = ProjectState {
+            project_uri: Some(project_uri),
+            owners: vec![project_owner1],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Whitelist,
+            ..default_project()
+        };
+        let whitelist_state = WhitelistState {
+            max_entrants: MAX_WHITELIST_ENTRANTS,
+            entrants: vec![entrant1],
+            lottery: Some(Lottery {
+                seed_hash: [7; 32],
+                winner_count: 1,
+                registration_deadline: Timestamp::from_timestamp_millis(100),
+                seed: None,
+                winners: Vec::new(),
+            }),
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let mut initial_whitelist = state_builder.new_map();
+        initial_whitelist.insert(project_id.clone(), whitelist_state.clone());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            whitelist: initial_whitelist,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let mut expected_whitelist = state_builder.new_map();
+        expected_whitelist.insert(project_id.clone(), whitelist_state);
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            whitelist: expected_whitelist,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // XOR-folding hash mock, so that the committed hash of seed [7; 32] is [7; 32].
+        let crypto_primitives = TestCryptoPrimitives::new();
+        crypto_primitives.setup_hash_sha2_256_mock(|data| {
+            let mut hash = [0u8; 32];
+            for (i, byte) in data.iter().enumerate() {
+                hash[i % 32] ^= *byte;
+            }
+            HashSha2256(hash)
+        });
+
+        let params = RevealLotteryParams {
+            project_id,
+            seed: [8; 32],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result =
+            host.with_rollback(|host| contract_reveal_lottery(&ctx, host, &crypto_primitives));
+        claim_eq!(result, Err(Error::InvalidLotterySeed));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.reveal_lottery successfully store the revealed seed and draw
+    /// winners deterministically.
+    fn test_contract_reveal_lottery() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let entrant1 = AccountAddress([10; 32]);
+        let entrant2 = AccountAddress([11; 32]);
+        let entrant3 = AccountAddress([12; 32]);
+        let project_state = ProjectState {
+            project_uri: Some(project_uri),
+            owners: vec![project_owner1],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Whitelist,
+            ..default_project()
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let mut initial_whitelist = state_builder.new_map();
+        initial_whitelist.insert(
+            project_id.clone(),
+            WhitelistState {
+                max_entrants: MAX_WHITELIST_ENTRANTS,
+                entrants: vec![entrant1, entrant2, entrant3],
+                lottery: Some(Lottery {
+                    seed_hash: [7; 32],
+                    winner_count: 2,
+                    registration_deadline: Timestamp::from_timestamp_millis(100),
+                    seed: None,
+                    winners: Vec::new(),
+                }),
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            whitelist: initial_whitelist,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let mut expected_whitelist = state_builder.new_map();
+        expected_whitelist.insert(
+            project_id.clone(),
+            WhitelistState {
+                max_entrants: MAX_WHITELIST_ENTRANTS,
+                entrants: vec![entrant1, entrant2, entrant3],
+                lottery: Some(Lottery {
+                    seed_hash: [7; 32],
+                    winner_count: 2,
+                    registration_deadline: Timestamp::from_timestamp_millis(100),
+                    seed: Some([7; 32]),
+                    winners: vec![entrant3, entrant1],
+                }),
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            whitelist: expected_whitelist,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // XOR-folding hash mock, so that the committed hash of seed [7; 32] is [7; 32].
+        let crypto_primitives = TestCryptoPrimitives::new();
+        crypto_primitives.setup_hash_sha2_256_mock(|data| {
+            let mut hash = [0u8; 32];
+            for (i, byte) in data.iter().enumerate() {
+                hash[i % 32] ^= *byte;
+            }
+            HashSha2256(hash)
+        });
+
+        let params = RevealLotteryParams {
+            project_id,
+            seed: [7; 32],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_reveal_lottery(&ctx, &mut host, &crypto_primitives);
+        claim!(
+            result.is_ok(),
+            "test_contract_reveal_lottery: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.view_lottery_result returns the draw result of an account.
+    fn test_contract_view_lottery_result() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let entrant1 = AccountAddress([10; 32]);
+        let entrant2 = AccountAddress([11; 32]);
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_whitelist = state_builder.new_map();
+        initial_whitelist.insert(
+            project_id.clone(),
+            WhitelistState {
+                max_entrants: MAX_WHITELIST_ENTRANTS,
+                entrants: vec![entrant1, entrant2],
+                lottery: Some(Lottery {
+                    seed_hash: [7; 32],
+                    winner_count: 1,
+                    registration_deadline: Timestamp::from_timestamp_millis(100),
+                    seed: Some([7; 32]),
+                    winners: vec![entrant2],
+                }),
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            whitelist: initial_whitelist,
+            ..empty_state(&mut state_builder)
+        };
+        let host = TestHost::new(initial_state, state_builder);
+
+        for (addr, is_winner) in [(entrant1, false), (entrant2, true)] {
+            let mut ctx = TestReceiveContext::empty();
+            let params = ViewLotteryResultParams {
+                project_id: project_id.clone(),
+                addr,
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+            let result = contract_view_lottery_result(&ctx, &host);
+            claim_eq!(
+                result,
+                Ok(ViewLotteryResultResponse {
+                    seed_hash: [7; 32],
+                    seed: Some([7; 32]),
+                    is_entrant: true,
+                    is_winner,
+                })
+            );
+        }
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.set_allocation_tiers.
+    fn test_contract_set_allocation_tiers_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_state = ProjectState {
+            project_uri: Some(project_uri),
+            owners: vec![project_owner1],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Whitelist,
+            ..default_project()
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // tiers are not sorted by min_stake.
+        let params = SetAllocationTiersParams {
+            project_id,
+            tiers: vec![
+                AllocationTier {
+                    min_stake: 1000,
+                    max_allocation: 500,
+                },
+                AllocationTier {
+                    min_stake: 100,
+                    max_allocation: 100,
+                },
+            ],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_set_allocation_tiers(&ctx, host));
+        claim_eq!(result, Err(Error::InvalidAllocationTiers));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.set_allocation_tiers successfully update allocation tiers.
+    fn test_contract_set_allocation_tiers() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let tiers = vec![
+            AllocationTier {
+                min_stake: 100,
+                max_allocation: 100,
+            },
+            AllocationTier {
+                min_stake: 1000,
+                max_allocation: 500,
+            },
+        ];
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                allocation_tiers: tiers.clone(),
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = SetAllocationTiersParams { project_id, tiers };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_set_allocation_tiers(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_set_allocation_tiers: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.view_allocation returns the tier and cap of the stake queried
+    /// from overlay-staking.view_stake function.
+    fn test_contract_view_allocation() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let staker = AccountAddress([10; 32]);
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri),
+                owners: vec![project_owner1],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                allocation_tiers: vec![
+                    AllocationTier {
+                        min_stake: 100,
+                        max_allocation: 100,
+                    },
+                    AllocationTier {
+                        min_stake: 1000,
+                        max_allocation: 500,
+                    },
+                ],
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-staking.view_stake mock to return the stake between the two tiers.
+        host.setup_mock_entrypoint(
+            staking_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_stake".to_string()),
+            MockFn::returning_ok(StakeStateResponse { amount: 999 }),
+        );
+
+        let mut ctx = TestReceiveContext::empty();
+        let params = ViewAllocationParams {
+            project_id,
+            addr: staker,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_view_allocation(&ctx, &host);
+        claim_eq!(
+            result,
+            Ok(ViewAllocationResponse {
+                stake: 999,
+                tier: Some(0),
+                max_allocation: 100,
+            })
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.view_allocation rejects when invoking
+    /// overlay-staking.view_stake function fails.
+    fn test_contract_view_allocation_failed_invoke_staking_contract_view() {
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: None,
+                owners: vec![AccountAddress([7; 32])],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                allocation_tiers: vec![AllocationTier {
+                    min_stake: 100,
+                    max_allocation: 100,
+                }],
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin: AccountAddress([1; 32]),
+            staking_contract_addr,
+            user_contract_addr: ContractAddress::new(1001, 0),
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let mut ctx = TestReceiveContext::empty();
+        let params = ViewAllocationParams {
+            project_id,
+            addr: AccountAddress([10; 32]),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+
+        for error in [
+            CallContractError::Trap,
+            CallContractError::MissingEntrypoint,
+        ] {
+            host.setup_mock_entrypoint(
+                staking_contract_addr,
+                OwnedEntrypointName::new_unchecked("view_stake".to_string()),
+                MockFn::returning_err::<()>(error),
+            );
+            let result = contract_view_allocation(&ctx, &host);
+            claim_eq!(result, Err(Error::FailedInvokeStakingContractView));
+        }
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.apply_signed_action.
+    fn test_contract_apply_signed_action_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let self_address = ContractAddress::new(999, 0);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let next_project_uri = ProjectUri {
+            url: "https://overlay.global/v2".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_state = ProjectState {
+            project_uri: Some(project_uri),
+            owners: vec![project_owner1],
+            pub_key: Some(PublicKeyEd25519([9; 32])),
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Whitelist,
+            signed_action_nonce: 1,
+            ..default_project()
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_self_address(self_address);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let crypto_primitives = TestCryptoPrimitives::new();
+        crypto_primitives.setup_verify_ed25519_signature_mock(|_, _, _| true);
+
+        // the signed nonce has already been used.
+        let params = ApplySignedActionParams {
+            signature: SignatureEd25519([1; 64]),
+            message: SignedActionMessage {
+                contract_address: self_address,
+                project_id,
+                nonce: 0,
+                action: SignedProjectAction::UpdateProjectUri(next_project_uri),
+            },
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result =
+            host.with_rollback(|host| contract_apply_signed_action(&ctx, host, &crypto_primitives));
+        claim_eq!(result, Err(Error::InvalidNonce));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.apply_signed_action successfully propose the signed project URI
+    /// and increment the nonce of the project.
+    fn test_contract_apply_signed_action() {
+        let admin = AccountAddress([1; 32]);
+        let self_address = ContractAddress::new(999, 0);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let next_project_uri = ProjectUri {
+            url: "https://overlay.global/v2".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let pub_key = PublicKeyEd25519([9; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_self_address(self_address);
+        ctx.set_invoker(project_owner1);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1],
+                pub_key: Some(pub_key),
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri),
+                owners: vec![project_owner1],
+                pub_key: Some(pub_key),
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                signed_action_nonce: 1,
+                pending_project_uri: Some(next_project_uri.clone()),
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let crypto_primitives = TestCryptoPrimitives::new();
+        crypto_primitives.setup_verify_ed25519_signature_mock(move |key, _, _| key == pub_key);
+
+        let params = ApplySignedActionParams {
+            signature: SignatureEd25519([1; 64]),
+            message: SignedActionMessage {
+                contract_address: self_address,
+                project_id,
+                nonce: 0,
+                action: SignedProjectAction::UpdateProjectUri(next_project_uri),
+            },
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_apply_signed_action(&ctx, &mut host, &crypto_primitives);
+        claim!(
+            result.is_ok(),
+            "test_contract_apply_signed_action: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.rotate_pub_key.
+    fn test_contract_rotate_pub_key_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_state = ProjectState {
+            project_uri: Some(project_uri),
+            owners: vec![project_owner1],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Whitelist,
+            ..default_project()
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(20));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = RotatePubKeyParams {
+            project_id,
+            pub_key: PublicKeyEd25519([10; 32]),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_rotate_pub_key(&ctx, host));
+        claim_eq!(result, Err(Error::PublicKeyNotFound));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.rotate_pub_key successfully revoke the active public key and
+    /// activate the new one.
+    fn test_contract_rotate_pub_key() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let old_pub_key = PublicKeyEd25519([9; 32]);
+        let new_pub_key = PublicKeyEd25519([10; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(20));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1],
+                pub_key: Some(old_pub_key),
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                pub_keys: vec![PubKeyRecord {
+                    pub_key: old_pub_key,
+                    activated_at: Timestamp::from_timestamp_millis(10),
+                    revoked_at: None,
+                }],
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1],
+                pub_key: Some(new_pub_key),
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                pub_keys: vec![
+                    PubKeyRecord {
+                        pub_key: old_pub_key,
+                        activated_at: Timestamp::from_timestamp_millis(10),
+                        revoked_at: Some(Timestamp::from_timestamp_millis(20)),
+                    },
+                    PubKeyRecord {
+                        pub_key: new_pub_key,
+                        activated_at: Timestamp::from_timestamp_millis(20),
+                        revoked_at: None,
+                    },
+                ],
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = RotatePubKeyParams {
+            project_id,
+            pub_key: new_pub_key,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_rotate_pub_key(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_rotate_pub_key: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.revoke_pub_key called by an owner successfully revoke the active
+    /// public key.
+    fn test_contract_revoke_pub_key() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let pub_key = PublicKeyEd25519([9; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner1);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(20));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1],
+                pub_key: Some(pub_key),
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                pub_keys: vec![PubKeyRecord {
+                    pub_key,
+                    activated_at: Timestamp::from_timestamp_millis(10),
+                    revoked_at: None,
+                }],
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                pub_keys: vec![PubKeyRecord {
+                    pub_key,
+                    activated_at: Timestamp::from_timestamp_millis(10),
+                    revoked_at: Some(Timestamp::from_timestamp_millis(20)),
+                }],
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = RevokePubKeyParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_revoke_pub_key(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_revoke_pub_key: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.permit.
+    fn test_contract_permit_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let self_address = ContractAddress::new(999, 0);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_state = ProjectState {
+            project_uri: Some(project_uri),
+            owners: vec![project_owner1],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Candidate,
+            ..default_project()
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_self_address(self_address);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+        let mut logger = TestLogger::init();
+        let payload = to_bytes(&AddTokenAddrParams {
+            project_id,
+            token_addr: ContractAddress::new(2000, 0),
+        });
+        let message = PermitMessage {
+            contract_address: self_address,
+            nonce: 0,
+            timestamp: Timestamp::from_timestamp_millis(200),
+            entry_point: OwnedEntrypointName::new_unchecked("add_token_addr".to_string()),
+            payload,
+        };
+        let crypto_primitives = TestCryptoPrimitives::new();
+
+        // the message has been expired.
+        let params = PermitParams {
+            signature: AccountSignatures {
+                sigs: collections::BTreeMap::new(),
+            },
+            signer: project_owner1,
+            message: PermitMessage {
+                timestamp: Timestamp::from_timestamp_millis(100),
+                ..message.clone()
+            },
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result =
+            host.with_rollback(|host| contract_permit(&ctx, host, &mut logger, &crypto_primitives));
+        claim_eq!(result, Err(Error::Expired));
+        claim!(logger.logs.is_empty(), "Unexpected event has been logged.");
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+
+        // the signed nonce does not match with the current nonce of the signer.
+        let params = PermitParams {
+            signature: AccountSignatures {
+                sigs: collections::BTreeMap::new(),
+            },
+            signer: project_owner1,
+            message: PermitMessage {
+                nonce: 1,
+                ..message
+            },
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result =
+            host.with_rollback(|host| contract_permit(&ctx, host, &mut logger, &crypto_primitives));
+        claim_eq!(result, Err(Error::InvalidNonce));
+        claim!(logger.logs.is_empty(), "Unexpected event has been logged.");
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that a checked permit message consumes the nonce of the signer and invokes each of the
+    /// supported entrypoints on behalf of the signer.
+    fn test_apply_permit_message() {
+        let admin = AccountAddress([1; 32]);
+        let self_address = ContractAddress::new(999, 0);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let vesting_contract_addr = ContractAddress::new(1002, 0);
+        let token_addr = ContractAddress::new(2000, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let new_project_uri = ProjectUri {
+            url: "https://overlay.global/new".into(),
+            hash: Some([1; 32]),
+        };
+        let schedule = VestingSchedule {
+            cliff: Timestamp::from_timestamp_millis(100),
+            tranches: vec![
+                (Timestamp::from_timestamp_millis(100), 5000),
+                (Timestamp::from_timestamp_millis(200), 5000),
+            ],
+            vesting_contract_addr,
+        };
+        let profile = ProjectProfile {
+            name: Some("Overlay".into()),
+            ticker: Some("OVL".into()),
+            category: Some("DeFi".into()),
+            chain: Some("Concordium".into()),
+            total_supply: Some(1_000_000),
+            website_hash: Some([1; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri),
+                owners: vec![project_owner1],
+                pub_key: None,
+                token_addr: Some(token_addr),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                pending_vesting_schedule: Some(schedule.clone()),
+                pending_project_uri: Some(new_project_uri.clone()),
+                pending_profile: Some(profile.clone()),
+                ..default_project()
+            },
+        );
+        let mut expected_nonces = state_builder.new_map();
+        expected_nonces.insert(project_owner1, 4);
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            nonces: expected_nonces,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let payloads = vec![
+            (
+                "add_token_addr",
+                to_bytes(&AddTokenAddrParams {
+                    project_id: project_id.clone(),
+                    token_addr,
+                }),
+            ),
+            (
+                "propose_vesting_schedule",
+                to_bytes(&ProposeVestingScheduleParams {
+                    project_id: project_id.clone(),
+                    schedule,
+                }),
+            ),
+            (
+                "propose_project_uri",
+                to_bytes(&ProposeProjectUriParams {
+                    project_id: project_id.clone(),
+                    project_uri: new_project_uri,
+                }),
+            ),
+            (
+                "propose_project_profile",
+                to_bytes(&ProposeProjectProfileParams {
+                    project_id,
+                    profile,
+                }),
+            ),
+        ];
+        let messages: Vec<PermitMessage> = payloads
+            .into_iter()
+            .enumerate()
+            .map(|(nonce, (entry_point, payload))| PermitMessage {
+                contract_address: self_address,
+                nonce: nonce as u64,
+                timestamp: Timestamp::from_timestamp_millis(200),
+                entry_point: OwnedEntrypointName::new_unchecked(entry_point.to_string()),
+                payload,
+            })
+            .collect();
+        for message in messages {
+            let result = apply_permit_message(host.state_mut(), project_owner1, &message);
+            claim!(
+                result.is_ok(),
+                "test_apply_permit_message: Results in rejection"
+            );
+        }
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on applying a permit message whose signed
+    /// entrypoint is not one of SUPPORTS_PERMIT_ENTRYPOINTS.
+    fn test_apply_permit_message_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let self_address = ContractAddress::new(999, 0);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_state = ProjectState {
+            project_uri: Some(project_uri),
+            owners: vec![project_owner1],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Candidate,
+            ..default_project()
+        };
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let message = PermitMessage {
+            contract_address: self_address,
+            nonce: 0,
+            timestamp: Timestamp::from_timestamp_millis(200),
+            entry_point: OwnedEntrypointName::new_unchecked("update_owners".to_string()),
+            payload: to_bytes(&UpdateOwnersParams {
+                project_id,
+                owners: vec![project_owner1],
+            }),
+        };
+        let result = host
+            .with_rollback(|host| apply_permit_message(host.state_mut(), project_owner1, &message));
+        claim_eq!(result, Err(Error::UnsupportedEntrypoint));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.supportsPermit returns support results of the inputted
+    /// entrypoints.
+    fn test_contract_supports_permit() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let host = TestHost::new(initial_state, state_builder);
+
+        let mut ctx = TestReceiveContext::empty();
+        let params = SupportsPermitParams {
+            queries: vec![
+                OwnedEntrypointName::new_unchecked("add_token_addr".to_string()),
+                OwnedEntrypointName::new_unchecked("update_owners".to_string()),
+            ],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_supports_permit(&ctx, &host);
+        claim_eq!(
+            result,
+            Ok(SupportsPermitResponse {
+                results: vec![SupportResult::Support, SupportResult::NoSupport],
+            })
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.supports returns support results of the inputted standards.
+    fn test_contract_supports() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let host = TestHost::new(initial_state, state_builder);
+
+        let mut ctx = TestReceiveContext::empty();
+        let params = SupportsParams {
+            queries: vec![
+                StandardIdentifier { id: "CIS-0".into() },
+                StandardIdentifier { id: "CIS-3".into() },
+                StandardIdentifier { id: "CIS-2".into() },
+            ],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_supports(&ctx, &host);
+        claim_eq!(
+            result,
+            Ok(SupportsResponse {
+                results: vec![
+                    SupportResult::Support,
+                    SupportResult::Support,
+                    SupportResult::NoSupport
+                ],
+            })
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.nonceOf returns current nonces of the inputted accounts.
+    fn test_contract_nonce_of() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let signer1 = AccountAddress([7; 32]);
+        let signer2 = AccountAddress([8; 32]);
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_nonces = state_builder.new_map();
+        initial_nonces.insert(signer1, 3);
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            nonces: initial_nonces,
+            ..empty_state(&mut state_builder)
+        };
+        let host = TestHost::new(initial_state, state_builder);
+
+        let mut ctx = TestReceiveContext::empty();
+        let params = NonceOfParams {
+            queries: vec![signer1, signer2],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_nonce_of(&ctx, &host);
+        claim_eq!(result, Ok(NonceOfResponse { nonces: vec![3, 0] }));
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.propose_project_uri.
+    fn test_contract_propose_project_uri_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let new_project_uri = ProjectUri {
+            url: "https://overlay.global/projects/new".into(),
+            hash: Some([0; 32]),
+        };
+        let not_owner = AccountAddress([9; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(not_owner);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ProposeProjectUriParams {
+            project_id,
+            project_uri: new_project_uri,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_propose_project_uri(&ctx, host));
+        claim_eq!(result, Err(Error::InvalidCaller));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.propose_project_uri successfully update project's pending
+    /// project uri.
+    fn test_contract_propose_project_uri() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let new_project_uri = ProjectUri {
+            url: "https://overlay.global/projects/new".into(),
+            hash: Some([0; 32]),
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner2);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                pending_project_uri: Some(new_project_uri.clone()),
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ProposeProjectUriParams {
+            project_id,
+            project_uri: new_project_uri,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_propose_project_uri(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_propose_project_uri: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.approve_project_uri.
+    fn test_contract_approve_project_uri_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ApproveProjectUriParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_approve_project_uri(&ctx, host));
+        claim_eq!(result, Err(Error::ProjectUriNotProposed));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that the project URI of a project which has left Candidate can not be changed to a
+    /// URI without hash through overlay-projects.propose_project_uri, approve_project_uri or
+    /// apply_signed_action.
+    fn test_contract_project_uri_missing_metadata_hash() {
+        let self_address = ContractAddress::new(999, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_owner1 = AccountAddress([7; 32]);
+        let pub_key = PublicKeyEd25519([9; 32]);
+        let unhashed_project_uri = ProjectUri {
+            url: "https://overlay.global/projects/new".into(),
+            hash: None,
+        };
+        let project_state = ProjectState {
+            project_uri: Some(ProjectUri {
+                url: "https://overlay.global/".into(),
+                hash: Some([0; 32]),
+            }),
+            owners: vec![project_owner1],
+            pub_key: Some(pub_key),
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            status: ProjectStatus::Whitelist,
+            pending_project_uri: Some(unhashed_project_uri.clone()),
+            ..default_project()
+        };
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let initial_state = State {
+            admin: AccountAddress([1; 32]),
+            staking_contract_addr: ContractAddress::new(1000, 0),
+            user_contract_addr: ContractAddress::new(1001, 0),
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let expected_state = State {
+            admin: AccountAddress([1; 32]),
+            staking_contract_addr: ContractAddress::new(1000, 0),
+            user_contract_addr: ContractAddress::new(1001, 0),
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner1);
+        let params = ProposeProjectUriParams {
+            project_id: project_id.clone(),
+            project_uri: unhashed_project_uri.clone(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_propose_project_uri(&ctx, host));
+        claim_eq!(result, Err(Error::MissingMetadataHash));
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(host.state().admin);
+        let params = ApproveProjectUriParams {
+            project_id: project_id.clone(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_approve_project_uri(&ctx, host));
+        claim_eq!(result, Err(Error::MissingMetadataHash));
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_self_address(self_address);
+        let crypto_primitives = TestCryptoPrimitives::new();
+        crypto_primitives.setup_verify_ed25519_signature_mock(move |key, _, _| key == pub_key);
+        let params = ApplySignedActionParams {
+            signature: SignatureEd25519([1; 64]),
+            message: SignedActionMessage {
+                contract_address: self_address,
+                project_id,
+                nonce: 0,
+                action: SignedProjectAction::UpdateProjectUri(unhashed_project_uri),
+            },
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result =
+            host.with_rollback(|host| contract_apply_signed_action(&ctx, host, &crypto_primitives));
+        claim_eq!(result, Err(Error::MissingMetadataHash));
+
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.approve_project_uri successfully apply project's pending project
+    /// uri.
+    fn test_contract_approve_project_uri() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let new_project_uri = ProjectUri {
+            url: "https://overlay.global/projects/new".into(),
+            hash: Some([0; 32]),
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                pending_project_uri: Some(new_project_uri.clone()),
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_metadata_history = state_builder.new_map();
+        expected_metadata_history.insert(
+            project_id.clone(),
+            vec![MetadataRecord {
+                uri: new_project_uri.clone(),
+                timestamp: Timestamp::from_timestamp_millis(100),
+                changed_by: admin,
+            }],
+        );
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(new_project_uri),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            metadata_history: expected_metadata_history,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ApproveProjectUriParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_approve_project_uri(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_approve_project_uri: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.reject_project_uri successfully discard project's pending project
+    /// uri.
+    fn test_contract_reject_project_uri() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let new_project_uri = ProjectUri {
+            url: "https://overlay.global/projects/new".into(),
+            hash: Some([0; 32]),
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                pending_project_uri: Some(new_project_uri),
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = RejectProjectUriParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_reject_project_uri(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_reject_project_uri: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.validate_project_admin rejects the project whose project uri does
+    /// not have its metadata hash.
+    fn test_contract_validate_project_admin_without_metadata_hash() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let validator = AccountAddress([3; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(ProjectUri {
+                    url: "https://overlay.global/".into(),
+                    hash: None,
+                }),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(ProjectUri {
+                    url: "https://overlay.global/".into(),
+                    hash: None,
+                }),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+
+        let params = ValidateProjectAdminParams {
+            validator,
+            project_id,
+            owners: vec![project_owner1, project_owner2],
+            token_addr: None,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_validate_project_admin(&ctx, host));
+        claim_eq!(result, Err(Error::MissingMetadataHash));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.view_project_metadata_history returns the inputted range of the
+    /// project uri history.
+    fn test_contract_view_project_metadata_history() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let history: Vec<MetadataRecord> = (0..3u8)
+            .map(|i| MetadataRecord {
+                uri: ProjectUri {
+                    url: "https://overlay.global/".into(),
+                    hash: Some([i; 32]),
+                },
+                timestamp: Timestamp::from_timestamp_millis(100 * i as u64),
+                changed_by: admin,
+            })
+            .collect();
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(history[2].uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let mut initial_metadata_history = state_builder.new_map();
+        initial_metadata_history.insert(project_id.clone(), history.clone());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            metadata_history: initial_metadata_history,
+            ..empty_state(&mut state_builder)
+        };
+        let host = TestHost::new(initial_state, state_builder);
+
+        for (from, limit, expected) in [
+            (0, 10, history.clone()),
+            (1, 1, vec![history[1].clone()]),
+            (3, 10, Vec::new()),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            let params = ViewProjectMetadataHistoryParams {
+                project_id: project_id.clone(),
+                from,
+                limit,
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+            let result = contract_view_project_metadata_history(&ctx, &host);
+            claim_eq!(result, Ok(expected));
+        }
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.propose_project_profile.
+    fn test_contract_propose_project_profile_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        // ticker must consist of uppercase letters and digits.
+        let profile = ProjectProfile {
+            name: Some("Overlay".into()),
+            ticker: Some("ovl".into()),
+            category: Some("DeFi".into()),
+            chain: Some("Concordium".into()),
+            total_supply: Some(1_000_000),
+            website_hash: Some([1; 32]),
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner1);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ProposeProjectProfileParams {
+            project_id,
+            profile,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_propose_project_profile(&ctx, host));
+        claim_eq!(result, Err(Error::InvalidProjectProfile));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.propose_project_profile successfully update project's pending
+    /// profile.
+    fn test_contract_propose_project_profile() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let profile = ProjectProfile {
+            name: Some("Overlay".into()),
+            ticker: Some("OVL".into()),
+            category: Some("DeFi".into()),
+            chain: Some("Concordium".into()),
+            total_supply: Some(1_000_000),
+            website_hash: Some([1; 32]),
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner1);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                pending_profile: Some(profile.clone()),
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ProposeProjectProfileParams {
+            project_id,
+            profile,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_propose_project_profile(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_propose_project_profile: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.approve_project_profile successfully apply project's pending
+    /// profile.
+    fn test_contract_approve_project_profile() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let profile = ProjectProfile {
+            name: Some("Overlay".into()),
+            ticker: Some("OVL".into()),
+            category: Some("DeFi".into()),
+            chain: Some("Concordium".into()),
+            total_supply: Some(1_000_000),
+            website_hash: Some([1; 32]),
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                pending_profile: Some(profile.clone()),
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                profile,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ApproveProjectProfileParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_approve_project_profile(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_approve_project_profile: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.add_tag.
+    fn test_contract_add_tag_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let mut initial_tags = state_builder.new_map();
+        initial_tags.insert("DeFi".into(), Vec::new());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            tags: initial_tags,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let mut expected_tags = state_builder.new_map();
+        expected_tags.insert("DeFi".into(), Vec::new());
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            tags: expected_tags,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = AddTagParams { tag: "DeFi".into() };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_add_tag(&ctx, host));
+        claim_eq!(result, Err(Error::TagAlreadyExists));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.add_tag successfully add the inputted tag.
+    fn test_contract_add_tag() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let mut initial_tags = state_builder.new_map();
+        initial_tags.insert("DeFi".into(), Vec::new());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            tags: initial_tags,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let mut expected_tags = state_builder.new_map();
+        expected_tags.insert("DeFi".into(), Vec::new());
+        expected_tags.insert("Gaming".into(), Vec::new());
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            tags: expected_tags,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = AddTagParams {
+            tag: "Gaming".into(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_add_tag(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_add_tag: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking
+    /// overlay-projects.set_project_tags.
+    fn test_contract_set_project_tags_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                tags: vec!["DeFi".into()],
+                ..default_project()
+            },
+        );
+        let mut initial_tags = state_builder.new_map();
+        initial_tags.insert("DeFi".into(), vec![project_id.clone()]);
+        initial_tags.insert("Gaming".into(), Vec::new());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            tags: initial_tags,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                tags: vec!["DeFi".into()],
+                ..default_project()
+            },
+        );
+        let mut expected_tags = state_builder.new_map();
+        expected_tags.insert("DeFi".into(), vec![project_id.clone()]);
+        expected_tags.insert("Gaming".into(), Vec::new());
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            tags: expected_tags,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = SetProjectTagsParams {
+            project_id,
+            tags: vec!["Gaming".into(), "Unknown".into()],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_set_project_tags(&ctx, host));
+        claim_eq!(result, Err(Error::TagNotFound));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.set_project_tags successfully replace project's tags and update
+    /// the tag index.
+    fn test_contract_set_project_tags() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                tags: vec!["DeFi".into()],
+                ..default_project()
+            },
+        );
+        let mut initial_tags = state_builder.new_map();
+        initial_tags.insert("DeFi".into(), vec![project_id.clone()]);
+        initial_tags.insert("Gaming".into(), Vec::new());
+        initial_tags.insert("Infra".into(), Vec::new());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            tags: initial_tags,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                tags: vec!["Gaming".into(), "Infra".into()],
+                ..default_project()
+            },
+        );
+        let mut expected_tags = state_builder.new_map();
+        expected_tags.insert("DeFi".into(), Vec::new());
+        expected_tags.insert("Gaming".into(), vec![project_id.clone()]);
+        expected_tags.insert("Infra".into(), vec![project_id.clone()]);
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            tags: expected_tags,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = SetProjectTagsParams {
+            project_id,
+            tags: vec!["Infra".into(), "Gaming".into(), "Infra".into()],
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_set_project_tags(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_set_project_tags: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.remove_tag successfully remove the inputted tag and unassign it
+    /// from projects.
+    fn test_contract_remove_tag() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                tags: vec!["DeFi".into(), "Gaming".into()],
+                ..default_project()
+            },
+        );
+        let mut initial_tags = state_builder.new_map();
+        initial_tags.insert("DeFi".into(), vec![project_id.clone()]);
+        initial_tags.insert("Gaming".into(), vec![project_id.clone()]);
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            tags: initial_tags,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                tags: vec!["Gaming".into()],
+                ..default_project()
+            },
+        );
+        let mut expected_tags = state_builder.new_map();
+        expected_tags.insert("Gaming".into(), vec![project_id.clone()]);
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            tags: expected_tags,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = RemoveTagParams { tag: "DeFi".into() };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_remove_tag(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_remove_tag: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.view_projects_by_tag returns the inputted range of project ids
+    /// tagged with the inputted tag.
+    fn test_contract_view_projects_by_tag() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_ids: Vec<ProjectId> = vec![
+            "dlsfjj-x87877xjjk".into(),
+            "dlsfjj-x87877xjjl".into(),
+            "dlsfjj-x87877xjjn".into(),
+        ];
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_tags = state_builder.new_map();
+        initial_tags.insert("DeFi".into(), project_ids.clone());
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            tags: initial_tags,
+            ..empty_state(&mut state_builder)
+        };
+        let host = TestHost::new(initial_state, state_builder);
+
+        for (from, limit, expected) in [
+            (0, 10, project_ids.clone()),
+            (1, 1, vec![project_ids[1].clone()]),
+            (3, 10, Vec::new()),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            let params = ViewProjectsByTagParams {
+                tag: "DeFi".into(),
+                from,
+                limit,
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+            let result = contract_view_projects_by_tag(&ctx, &host);
+            claim_eq!(result, Ok(expected));
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.apply_curate_project rejects project ids which do not follow the
+    /// project id grammar.
+    fn test_contract_apply_curate_project_with_invalid_id() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let too_long_id = "a".repeat(MAX_PROJECT_ID_LENGTH + 1);
+        for project_id in [
+            "",
+            "  ",
+            "DLSFJJ&&X87877XJJK",
+            "-dlsfjj",
+            "dlsfjj--x87877",
+            too_long_id.as_str(),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(admin);
+            ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+            let params = ApplyCurateProjectParams {
+                project_id: Some(project_id.into()),
+                project_uri: project_uri.clone(),
+                owners: vec![project_owner1],
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+            let result = host.with_rollback(|host| contract_apply_curate_project(&ctx, host));
+            claim_eq!(result, Err(Error::InvalidProjectId));
+        }
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects entrypoints normalize the inputted project id before looking the
+    /// project up, and reject project ids which do not follow the project id grammar.
+    fn test_contract_lookup_with_normalized_id() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_owner1 = AccountAddress([7; 32]);
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: None,
+                owners: vec![project_owner1],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            mock_update_project_status(project_id.clone(), ProjectStatus::OnSale),
+        );
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let params = StartSaleParams {
+            project_id: " DLSFJJ-X87877XJJN ".into(),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_start_sale(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_lookup_with_normalized_id: Results in rejection"
+        );
+
+        for (project_id, expected) in [
+            ("DLSFJJ-X87877XJJN", Ok(ProjectStatus::OnSale)),
+            ("DLSFJJ&&X87877XJJN", Err(Error::InvalidProjectId)),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            let params = ViewProjectParams {
+                project_id: project_id.into(),
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+            let result = contract_view_project(&ctx, &host);
+            claim_eq!(result.map(|project| project.status), expected);
+        }
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.apply_curate_project normalizes the inputted project id, and
+    /// generates a sequential project id skipping registered ids if it is not inputted.
+    fn test_contract_apply_curate_project_with_normalized_and_generated_id() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            "project-1".into(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            "project-1".into(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        expected_project.insert(
+            "overlay-launch".into(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                owner_threshold: 2,
+                ..default_project()
+            },
+        );
+        expected_project.insert(
+            "project-2".into(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                owner_threshold: 2,
+                ..default_project()
+            },
+        );
+        let mut expected_metadata_history = state_builder.new_map();
+        expected_metadata_history.insert(
+            "overlay-launch".into(),
+            vec![MetadataRecord {
+                uri: project_uri.clone(),
+                timestamp: Timestamp::from_timestamp_millis(100),
+                changed_by: admin,
+            }],
+        );
+        expected_metadata_history.insert(
+            "project-2".into(),
+            vec![MetadataRecord {
+                uri: project_uri.clone(),
+                timestamp: Timestamp::from_timestamp_millis(100),
+                changed_by: admin,
+            }],
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            metadata_history: expected_metadata_history,
+            project_id_counter: 2,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            MockFn::returning_ok(()),
+        );
+
+        for (project_id, expected_id) in [
+            (Some("  Overlay-Launch ".into()), "overlay-launch"),
+            (None, "project-2"),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(admin);
+            ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+            let params = ApplyCurateProjectParams {
+                project_id,
+                project_uri: project_uri.clone(),
+                owners: vec![project_owner1, project_owner2],
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+            let result = contract_apply_curate_project(&ctx, &mut host);
+            claim_eq!(result, Ok(expected_id.into()));
+        }
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.update_owners rejects empty, duplicated or too many owners.
+    fn test_contract_update_owners_with_invalid_owners() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let project_owner3 = AccountAddress([9; 32]);
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            max_owners: 2,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            max_owners: 2,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        for (owners, error) in [
+            (Vec::new(), Error::EmptyOwners),
+            (vec![project_owner1, project_owner1], Error::DuplicateOwners),
+            (
+                vec![project_owner1, project_owner2, project_owner3],
+                Error::TooManyOwners,
+            ),
+        ] {
+            let mut ctx = TestReceiveContext::empty();
+            ctx.set_invoker(admin);
+            let params = UpdateOwnersParams {
+                project_id: project_id.clone(),
+                owners,
+            };
+            let params_byte = to_bytes(&params);
+            ctx.set_parameter(&params_byte);
+            let result = host.with_rollback(|host| contract_update_owners(&ctx, host));
+            claim_eq!(result, Err(error));
+        }
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.set_max_owners successfully update max number of owners.
+    fn test_contract_set_max_owners() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
+        };
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            max_owners: 3,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = SetMaxOwnersParams { max_owners: 3 };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_set_max_owners(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_set_max_owners: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.propose_owners.
+    fn test_contract_propose_owners_with_rollback() {
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let project_state = ProjectState {
+            project_uri: None,
+            owners: vec![project_owner1, project_owner2],
+            pub_key: None,
+            token_addr: None,
+            seed_nft_addr: None,
+            sale_addr: None,
+            owner_threshold: 2,
+            status: ProjectStatus::Candidate,
+            ..default_project()
+        };
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner1);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(project_id.clone(), project_state.clone());
+        let initial_state = State {
+            admin: AccountAddress([1; 32]),
+            staking_contract_addr: ContractAddress::new(1000, 0),
+            user_contract_addr: ContractAddress::new(1001, 0),
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(project_id.clone(), project_state);
+        let expected_state = State {
+            admin: AccountAddress([1; 32]),
+            staking_contract_addr: ContractAddress::new(1000, 0),
+            user_contract_addr: ContractAddress::new(1001, 0),
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ProposeOwnersParams {
+            project_id,
+            owners: vec![project_owner1],
+            owner_threshold: 1,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_propose_owners(&ctx, host));
+        claim_eq!(result, Err(Error::InvalidStatus));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.propose_owners successfully store the owner proposal approved by
+    /// the caller.
+    fn test_contract_propose_owners() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let project_owner3 = AccountAddress([9; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner1);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                owner_threshold: 2,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                owner_threshold: 2,
+                status: ProjectStatus::Whitelist,
+                owner_proposal: Some(OwnerProposal {
+                    owners: vec![project_owner2, project_owner3],
+                    owner_threshold: 1,
+                    approvals: vec![project_owner1],
+                }),
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ProposeOwnersParams {
+            project_id,
+            owners: vec![project_owner2, project_owner3],
+            owner_threshold: 1,
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_propose_owners(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_propose_owners: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.approve_owners.
+    fn test_contract_approve_owners_with_rollback() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let project_owner3 = AccountAddress([9; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner1);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                owner_threshold: 2,
+                owner_proposal: Some(OwnerProposal {
+                    owners: vec![project_owner2, project_owner3],
+                    owner_threshold: 1,
+                    approvals: vec![project_owner1],
+                }),
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                owner_threshold: 2,
+                owner_proposal: Some(OwnerProposal {
+                    owners: vec![project_owner2, project_owner3],
+                    owner_threshold: 1,
+                    approvals: vec![project_owner1],
+                }),
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ApproveOwnersParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_approve_owners(&ctx, host));
+        claim_eq!(result, Err(Error::AlreadyApproved));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.approve_owners successfully apply the owner proposal once it
+    /// reaches the owner threshold.
+    fn test_contract_approve_owners() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let project_owner3 = AccountAddress([9; 32]);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner2);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                owner_threshold: 2,
+                owner_proposal: Some(OwnerProposal {
+                    owners: vec![project_owner2, project_owner3],
+                    owner_threshold: 1,
+                    approvals: vec![project_owner1],
+                }),
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner2, project_owner3],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ApproveOwnersParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_approve_owners(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_approve_owners: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.validate_project_admin rejects a token address different from the
+    /// one the project already has.
+    fn test_contract_validate_project_admin_with_conflicting_token_addr() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let validator = AccountAddress([3; 32]);
+        let project_owner3 = AccountAddress([9; 32]);
+        let token_addr = ContractAddress::new(2000, 0);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: Some(ContractAddress::new(2001, 0)),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: Some(ContractAddress::new(2001, 0)),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+
+        let params = ValidateProjectAdminParams {
+            validator,
+            project_id,
+            owners: vec![project_owner3],
+            token_addr: Some(token_addr),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = host.with_rollback(|host| contract_validate_project_admin(&ctx, host));
+        claim_eq!(result, Err(Error::TokenAddrConflict));
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.validate_project_admin finalizes the inputted owners and token
+    /// address of the project.
+    fn test_contract_validate_project_admin_with_owners_and_token_addr() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let validator = AccountAddress([3; 32]);
+        let project_owner3 = AccountAddress([9; 32]);
+        let token_addr = ContractAddress::new(2000, 0);
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: initial_project,
+            ..empty_state(&mut state_builder)
+        };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner3],
+                pub_key: None,
+                token_addr: Some(token_addr),
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                validated_by: Some(validator),
+                ..default_project()
+            },
+        );
+        let mut expected_rewards = state_builder.new_map();
+        expected_rewards.insert(
+            validator,
+            RewardState {
+                pending: DEFAULT_WHITELIST_REWARD_POINTS,
+                claimed: 0,
+            },
+        );
+        let mut expected_validator_stats = state_builder.new_map();
+        expected_validator_stats.insert(
+            validator,
+            ProjectStats {
+                projects: 1,
+                whitelisted: 1,
+                sold_out: 0,
+                rejected: 0,
+            },
+        );
+        let expected_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: expected_project,
+            rewards: expected_rewards,
+            validator_stats: expected_validator_stats,
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: false,
+                is_validator: true,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            MockFn::returning_ok(()),
+        );
+        // set up overlay-users.validate.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("validate".to_string()),
+            MockFn::returning_ok(()),
+        );
 
-    fn ne(&self, other: &Self) -> bool {
-        !self.eq(other)
+        let params = ValidateProjectAdminParams {
+            validator,
+            project_id,
+            owners: vec![project_owner3],
+            token_addr: Some(token_addr),
+        };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_validate_project_admin(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_validate_project_admin_with_owners_and_token_addr: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
     }
-}
-
-#[concordium_cfg_test]
-mod tests {
-    use super::*;
-    use test_infrastructure::*;
 
     #[concordium_test]
-    /// Test that init succeeds.
-    fn test_init() {
-        // invoker will be an admin
-        let invoker = AccountAddress([1; 32]);
+    /// Test that overlay-projects.archive_project successfully move the project to the archive and
+    /// remove it from the tag index.
+    fn test_contract_archive_project() {
+        let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
 
-        let mut ctx = TestInitContext::empty();
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
         let mut state_builder = TestStateBuilder::new();
-        ctx.set_init_origin(invoker);
-
-        // prepare for expected state after init
-        let expected_state = State {
-            admin: invoker,
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                tags: vec!["DeFi".into()],
+                ..default_project()
+            },
+        );
+        let mut initial_tags = state_builder.new_map();
+        initial_tags.insert("DeFi".into(), vec![project_id.clone()]);
+        let initial_state = State {
+            admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: initial_project,
+            tags: initial_tags,
+            ..empty_state(&mut state_builder)
         };
-
-        // create params
-        let params = InitParams {
+        let mut expected_archive = state_builder.new_map();
+        expected_archive.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                tags: vec!["DeFi".into()],
+                ..default_project()
+            },
+        );
+        let mut expected_tags = state_builder.new_map();
+        expected_tags.insert("DeFi".into(), Vec::new());
+        let expected_state = State {
+            admin,
             staking_contract_addr,
             user_contract_addr,
+            project: state_builder.new_map(),
+            tags: expected_tags,
+            archive: expected_archive,
+            ..empty_state(&mut state_builder)
         };
+        let mut host = TestHost::new(initial_state, state_builder);
+
+        let params = ArchiveProjectParams { project_id };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-
-        // execute init
-        let result = contract_init(&ctx, &mut state_builder);
-        // check init result
-        claim!(result.is_ok());
-        let actual_state = result.unwrap();
+        let result = contract_archive_project(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_archive_project: Results in rejection"
+        );
+        let actual_state = host.state();
         claim_eq!(
-            actual_state,
+            *actual_state,
             expected_state,
             "state has been changed unexpectedly..."
         );
     }
 
     #[concordium_test]
-    /// Test that with_rollback works for the state on invoking
-    /// overlay-projects.update_contract_state.
-    fn test_contract_update_contract_state_with_rollback() {
+    /// Test that with_rollback works for the state on invoking overlay-projects.delete_project.
+    fn test_contract_delete_project_with_rollback() {
         let admin = AccountAddress([1; 32]);
-        let invoker = AccountAddress([7; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let next_staking_contract_addr = ContractAddress::new(2000, 0);
-        let next_user_contract_addr = ContractAddress::new(2001, 0);
-        let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(invoker);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
 
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
         let initial_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: initial_project,
+            ..empty_state(&mut state_builder)
         };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
         let expected_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: expected_project,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = UpdateContractStateParams {
-            staking_contract_addr: next_staking_contract_addr,
-            user_contract_addr: next_user_contract_addr,
-        };
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("remove_project".to_string()),
+            MockFn::returning_ok(()),
+        );
+
+        let params = DeleteProjectParams { project_id };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_update_contract_state(&ctx, host));
+        let result = host.with_rollback(|host| contract_delete_project(&ctx, host));
+        claim_eq!(result, Err(Error::InvalidStatus));
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -1079,124 +13056,294 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that overlay-projects.update_contract_state successfully update
-    /// staking_contract_addr and user_contract_addr.
-    fn test_contract_update_contract_state() {
+    /// Test that overlay-projects.delete_project successfully delete the Candidate project and its
+    /// metadata history.
+    fn test_contract_delete_project() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let next_staking_contract_addr = ContractAddress::new(2000, 0);
-        let next_user_contract_addr = ContractAddress::new(2001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
         let mut ctx = TestReceiveContext::empty();
         ctx.set_invoker(admin);
-
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let mut initial_metadata_history = state_builder.new_map();
+        initial_metadata_history.insert(
+            project_id.clone(),
+            vec![MetadataRecord {
+                uri: project_uri.clone(),
+                timestamp: Timestamp::from_timestamp_millis(100),
+                changed_by: admin,
+            }],
+        );
         let initial_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: initial_project,
+            metadata_history: initial_metadata_history,
+            ..empty_state(&mut state_builder)
         };
         let expected_state = State {
             admin,
-            staking_contract_addr: next_staking_contract_addr,
-            user_contract_addr: next_user_contract_addr,
+            staking_contract_addr,
+            user_contract_addr,
             project: state_builder.new_map(),
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = UpdateContractStateParams {
-            staking_contract_addr: next_staking_contract_addr,
-            user_contract_addr: next_user_contract_addr,
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("remove_project".to_string()),
+            MockFn::returning_ok(()),
+        );
+
+        let params = DeleteProjectParams { project_id };
+        let params_byte = to_bytes(&params);
+        ctx.set_parameter(&params_byte);
+        let result = contract_delete_project(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_delete_project: Results in rejection"
+        );
+        let actual_state = host.state();
+        claim_eq!(
+            *actual_state,
+            expected_state,
+            "state has been changed unexpectedly..."
+        );
+    }
+
+    #[concordium_test]
+    /// Test that overlay-projects.view_project returns archived projects.
+    fn test_contract_view_project_archived() {
+        let admin = AccountAddress([1; 32]);
+        let staking_contract_addr = ContractAddress::new(1000, 0);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_archive = state_builder.new_map();
+        initial_archive.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        let initial_state = State {
+            admin,
+            staking_contract_addr,
+            user_contract_addr,
+            project: state_builder.new_map(),
+            archive: initial_archive,
+            ..empty_state(&mut state_builder)
         };
+        let host = TestHost::new(initial_state, state_builder);
+
+        let mut ctx = TestReceiveContext::empty();
+        let params = ViewProjectParams { project_id };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let result = contract_update_contract_state(&ctx, &mut host);
-        claim!(
-            result.is_ok(),
-            "test_contract_update_contract_state: Results in rejection"
-        );
-        let actual_state = host.state();
+        let result = contract_view_project(&ctx, &host);
         claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
+            result.map(|project| project.project_uri),
+            Ok(Some(project_uri))
         );
     }
 
     #[concordium_test]
-    /// Test that with_rollback works for the state on invoking
-    /// overlay-projects.transfer_admin.
-    fn test_contract_transfer_admin_with_rollback() {
+    /// Test that overlay-projects.view_pub_keys returns the public key history of archived
+    /// projects.
+    fn test_contract_view_pub_keys_archived() {
         let admin = AccountAddress([1; 32]);
-        let invoker = AccountAddress([8; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_owner1 = AccountAddress([7; 32]);
+        let pub_keys = vec![
+            PubKeyRecord {
+                pub_key: PublicKeyEd25519([9; 32]),
+                activated_at: Timestamp::from_timestamp_millis(10),
+                revoked_at: Some(Timestamp::from_timestamp_millis(20)),
+            },
+            PubKeyRecord {
+                pub_key: PublicKeyEd25519([10; 32]),
+                activated_at: Timestamp::from_timestamp_millis(20),
+                revoked_at: None,
+            },
+        ];
 
-        let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(invoker);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_archive = state_builder.new_map();
+        initial_archive.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: None,
+                owners: vec![project_owner1],
+                pub_key: Some(PublicKeyEd25519([10; 32])),
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                pub_keys: pub_keys.clone(),
+                ..default_project()
+            },
+        );
         let initial_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
             project: state_builder.new_map(),
+            archive: initial_archive,
+            ..empty_state(&mut state_builder)
         };
-        let expected_state = State {
-            admin,
-            staking_contract_addr,
-            user_contract_addr,
-            project: state_builder.new_map(),
-        };
-        let mut host = TestHost::new(initial_state, state_builder);
+        let host = TestHost::new(initial_state, state_builder);
 
-        let params = TransferAdminParams { admin: invoker };
+        let mut ctx = TestReceiveContext::empty();
+        let params = ViewPubKeysParams { project_id };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_transfer_admin(&ctx, host));
-        let actual_state = host.state();
-        claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
-        );
+        let result = contract_view_pub_keys(&ctx, &host);
+        claim_eq!(result, Ok(pub_keys));
     }
 
     #[concordium_test]
-    /// Test that overlay-projects.transfer_admin successfully update admin.
-    fn test_contract_transfer_admin() {
+    /// Test that with_rollback works for the state on invoking overlay-projects.merge_projects.
+    fn test_contract_merge_projects_with_rollback() {
         let admin = AccountAddress([1; 32]);
-        let admin_to_be_set = AccountAddress([2u8; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let drop_project_id: ProjectId = "dlsfjj-x87877xjjk".into();
+        let project_owner3 = AccountAddress([9; 32]);
 
         let mut ctx = TestReceiveContext::empty();
         ctx.set_invoker(admin);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        initial_project.insert(
+            drop_project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner3],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
         let initial_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: initial_project,
+            ..empty_state(&mut state_builder)
         };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        expected_project.insert(
+            drop_project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner3],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
         let expected_state = State {
-            admin: admin_to_be_set,
+            admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: expected_project,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = TransferAdminParams {
-            admin: admin_to_be_set,
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("merge_project".to_string()),
+            MockFn::returning_ok(()),
+        );
+
+        let params = MergeProjectsParams {
+            keep: project_id,
+            drop: drop_project_id,
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let result = contract_transfer_admin(&ctx, &mut host);
-        claim!(
-            result.is_ok(),
-            "test_contract_transfer_admin: Results in rejection"
-        );
+        let result = host.with_rollback(|host| contract_merge_projects(&ctx, host));
+        claim_eq!(result, Err(Error::InvalidStatus));
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -1206,43 +13353,164 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that with_rollback works for the state on invoking
-    /// overlay-projects.apply_curate_project.
-    fn test_contract_apply_curate_project_with_rollback() {
+    /// Test that overlay-projects.merge_projects successfully merge the duplicate project into the
+    /// surviving project and archive it.
+    fn test_contract_merge_projects() {
         let admin = AccountAddress([1; 32]);
-        let non_admin = AccountAddress([7u8; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "somethingdangerous".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
+        let drop_project_id: ProjectId = "dlsfjj-x87877xjjk".into();
+        let project_owner3 = AccountAddress([9; 32]);
 
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(non_admin);
+        ctx.set_invoker(admin);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                tags: vec!["DeFi".into()],
+                ..default_project()
+            },
+        );
+        initial_project.insert(
+            drop_project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner2, project_owner3],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                tags: vec!["Gaming".into()],
+                ..default_project()
+            },
+        );
+        let mut initial_metadata_history = state_builder.new_map();
+        initial_metadata_history.insert(
+            project_id.clone(),
+            vec![MetadataRecord {
+                uri: project_uri.clone(),
+                timestamp: Timestamp::from_timestamp_millis(100),
+                changed_by: admin,
+            }],
+        );
+        initial_metadata_history.insert(
+            drop_project_id.clone(),
+            vec![MetadataRecord {
+                uri: project_uri.clone(),
+                timestamp: Timestamp::from_timestamp_millis(200),
+                changed_by: admin,
+            }],
+        );
+        let mut initial_tags = state_builder.new_map();
+        initial_tags.insert("DeFi".into(), vec![project_id.clone()]);
+        initial_tags.insert("Gaming".into(), vec![drop_project_id.clone()]);
         let initial_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: initial_project,
+            metadata_history: initial_metadata_history,
+            tags: initial_tags,
+            ..empty_state(&mut state_builder)
         };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2, project_owner3],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                tags: vec!["DeFi".into(), "Gaming".into()],
+                owner_threshold: 2,
+                ..default_project()
+            },
+        );
+        let mut expected_archive = state_builder.new_map();
+        expected_archive.insert(
+            drop_project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner2, project_owner3],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                tags: vec!["Gaming".into()],
+                merged_into: Some(project_id.clone()),
+                ..default_project()
+            },
+        );
+        let mut expected_metadata_history = state_builder.new_map();
+        expected_metadata_history.insert(
+            project_id.clone(),
+            vec![MetadataRecord {
+                uri: project_uri.clone(),
+                timestamp: Timestamp::from_timestamp_millis(100),
+                changed_by: admin,
+            }],
+        );
+        expected_metadata_history.insert(
+            drop_project_id.clone(),
+            vec![MetadataRecord {
+                uri: project_uri.clone(),
+                timestamp: Timestamp::from_timestamp_millis(200),
+                changed_by: admin,
+            }],
+        );
+        let mut expected_tags = state_builder.new_map();
+        expected_tags.insert("DeFi".into(), vec![project_id.clone()]);
+        expected_tags.insert("Gaming".into(), vec![project_id.clone()]);
         let expected_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: expected_project,
+            metadata_history: expected_metadata_history,
+            tags: expected_tags,
+            archive: expected_archive,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = ApplyCurateProjectParams {
-            project_id,
-            project_uri,
-            owners: vec![project_owner1, project_owner2],
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("merge_project".to_string()),
+            MockFn::returning_ok(()),
+        );
+
+        let params = MergeProjectsParams {
+            keep: project_id,
+            drop: drop_project_id,
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_apply_curate_project(&ctx, host));
+        let result = contract_merge_projects(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_merge_projects: Results in rejection"
+        );
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -1252,24 +13520,67 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that overlay-projects.apply_curate_project successfully update project.
-    fn test_contract_apply_curate_project() {
+    /// Test that overlay-projects.merge_projects keeps the owners, the owner threshold and the owner
+    /// proposal of a surviving project past Candidate, and records the project uri it takes over.
+    fn test_contract_merge_projects_into_validated_project() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJK".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
-        let project_owner1 = AccountAddress([5; 32]);
-        let project_owner2 = AccountAddress([6; 32]);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let drop_project_id: ProjectId = "dlsfjj-x87877xjjk".into();
+        let project_owner3 = AccountAddress([9; 32]);
+        let owner_proposal = OwnerProposal {
+            owners: vec![project_owner1],
+            owner_threshold: 1,
+            approvals: vec![project_owner1],
+        };
+        let now = Timestamp::from_timestamp_millis(300);
 
         let mut ctx = TestReceiveContext::empty();
         ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(now);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: None,
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                owner_threshold: 2,
+                owner_proposal: Some(owner_proposal.clone()),
+                ..default_project()
+            },
+        );
+        initial_project.insert(
+            drop_project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner3],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
         let initial_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: initial_project,
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -1281,86 +13592,64 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Candidate,
+                status: ProjectStatus::Whitelist,
+                owner_threshold: 2,
+                owner_proposal: Some(owner_proposal),
+                ..default_project()
             },
         );
-        let expected_state = State {
-            admin,
-            staking_contract_addr,
-            user_contract_addr,
-            project: expected_project,
-        };
-        let mut host = TestHost::new(initial_state, state_builder);
-
-        let params = ApplyCurateProjectParams {
-            project_id,
-            project_uri,
-            owners: vec![project_owner1, project_owner2],
-        };
-        let params_byte = to_bytes(&params);
-        ctx.set_parameter(&params_byte);
-        let result = contract_apply_curate_project(&ctx, &mut host);
-        claim!(
-            result.is_ok(),
-            "test_contract_apply_curate_project: Results in rejection."
-        );
-        let actual_state = host.state();
-        claim_eq!(
-            *actual_state,
-            expected_state,
-            "state has been changed unexpectedly..."
+        let mut expected_archive = state_builder.new_map();
+        expected_archive.insert(
+            drop_project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner3],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                merged_into: Some(project_id.clone()),
+                ..default_project()
+            },
+        );
+        let mut expected_metadata_history = state_builder.new_map();
+        expected_metadata_history.insert(
+            project_id.clone(),
+            vec![MetadataRecord {
+                uri: project_uri,
+                timestamp: now,
+                changed_by: admin,
+            }],
         );
-    }
-
-    #[concordium_test]
-    /// Test that with_rollback works for the state on invoking overlay-projects.curate_project.
-    fn test_contract_curate_project_with_rollback() {
-        let admin = AccountAddress([1; 32]);
-        let staking_contract_addr = ContractAddress::new(1000, 0);
-        let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "somethingdangerous".into();
-        let project_owner1 = AccountAddress([7; 32]);
-        let project_owner2 = AccountAddress([8; 32]);
-
-        let mut ctx = TestReceiveContext::empty();
-        let mut state_builder = TestStateBuilder::new();
-        let initial_state = State {
-            admin,
-            staking_contract_addr,
-            user_contract_addr,
-            project: state_builder.new_map(),
-        };
         let expected_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: expected_project,
+            metadata_history: expected_metadata_history,
+            archive: expected_archive,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        // set up overlay-users.view_user mock to return non-curator user.
-        // this leads to InvalidCaller error.
         host.setup_mock_entrypoint(
             user_contract_addr,
-            OwnedEntrypointName::new_unchecked("view_user".to_string()),
-            MockFn::returning_ok(UserStateResponse {
-                is_curator: false,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
-            }),
+            OwnedEntrypointName::new_unchecked("merge_project".to_string()),
+            MockFn::returning_ok(()),
         );
 
-        let params = CurateProjectParams {
-            project_id,
-            project_uri,
-            owners: vec![project_owner1, project_owner2],
+        let params = MergeProjectsParams {
+            keep: project_id,
+            drop: drop_project_id,
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        ctx.set_sender(Address::Account(project_owner1));
-        let _ = host.with_rollback(|host| contract_curate_project(&ctx, host));
+        let result = contract_merge_projects(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_merge_projects_into_validated_project: Results in rejection"
+        );
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -1370,24 +13659,43 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that overlay-projects.curate_project successfully invoke overlay-users.curate function.
-    fn test_contract_curate_project() {
+    /// Test that with_rollback works for the state on invoking overlay-projects.add_listener.
+    fn test_contract_add_listener_with_rollback() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJK".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
-        let project_owner1 = AccountAddress([5; 32]);
-        let project_owner2 = AccountAddress([6; 32]);
-        let curator_address = AccountAddress([3; 32]);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let listener_contract_addr = ContractAddress::new(1002, 0);
 
         let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(project_owner1);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
         let initial_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: initial_project,
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -1400,6 +13708,7 @@ mod tests {
                 seed_nft_addr: None,
                 sale_addr: None,
                 status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -1407,41 +13716,19 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        // set up overlay-users.view_user mock to return curator user so that the curate func would
-        // be called by this func.
-        host.setup_mock_entrypoint(
-            user_contract_addr,
-            OwnedEntrypointName::new_unchecked("view_user".to_string()),
-            MockFn::returning_ok(UserStateResponse {
-                is_curator: true,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
-            }),
-        );
-        // set up overlay-users.curate.
-        host.setup_mock_entrypoint(
-            user_contract_addr,
-            OwnedEntrypointName::new_unchecked("curate".to_string()),
-            MockFn::returning_ok(()),
-        );
-
-        let params = CurateProjectParams {
-            project_id,
-            project_uri,
-            owners: vec![project_owner1, project_owner2],
+        let params = Listener {
+            address: listener_contract_addr,
+            entrypoint: OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
+            failure_policy: ListenerFailurePolicy::Ignore,
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        ctx.set_sender(Address::Account(curator_address));
-        let result: ContractResult<()> = contract_curate_project(&ctx, &mut host);
-        claim!(
-            result.is_ok(),
-            "test_contract_curate_project: Results in rejection."
-        );
+        let result = host.with_rollback(|host| contract_add_listener(&ctx, host));
+        claim_eq!(result, Err(Error::InvalidCaller));
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -1451,56 +13738,84 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that with_rollback works for the state on invoking
-    /// overlay-projects.curate_project_admin.
-    fn test_contract_curate_project_admin_with_rollback() {
+    /// Test that overlay-projects.add_listener appends the inputted listener.
+    fn test_contract_add_listener() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "somethingdangerous".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
+        let listener_contract_addr = ContractAddress::new(1002, 0);
 
         let mut ctx = TestReceiveContext::empty();
         ctx.set_invoker(admin);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
         let initial_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: initial_project,
+            ..empty_state(&mut state_builder)
         };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
         let expected_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: expected_project,
+            listeners: vec![Listener {
+                address: listener_contract_addr,
+                entrypoint: OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
+                failure_policy: ListenerFailurePolicy::Revert,
+            }],
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        // set up overlay-users.view_user mock to return non-curator user.
-        // this leads to InvalidCaller error.
-        host.setup_mock_entrypoint(
-            user_contract_addr,
-            OwnedEntrypointName::new_unchecked("view_user".to_string()),
-            MockFn::returning_ok(UserStateResponse {
-                is_curator: false,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
-            }),
-        );
-
-        let params = CurateProjectAdminParams {
-            curator: project_owner1,
-            project_id,
-            project_uri,
-            owners: vec![project_owner1, project_owner2],
+        let params = Listener {
+            address: listener_contract_addr,
+            entrypoint: OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
+            failure_policy: ListenerFailurePolicy::Revert,
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_curate_project_admin(&ctx, host));
+        let result = contract_add_listener(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_add_listener: Results in rejection"
+        );
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -1510,25 +13825,48 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that overlay-projects.curate_project_admin successfully finish its process.
-    fn test_contract_curate_project_admin() {
+    /// Test that overlay-projects.add_listener rejects the listener which has already been added.
+    fn test_contract_add_listener_already_exists() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJK".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
-        let project_owner1 = AccountAddress([5; 32]);
-        let project_owner2 = AccountAddress([6; 32]);
-        let curator = AccountAddress([3; 32]);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let listener_contract_addr = ContractAddress::new(1002, 0);
 
         let mut ctx = TestReceiveContext::empty();
         ctx.set_invoker(admin);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
         let initial_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: initial_project,
+            listeners: vec![Listener {
+                address: listener_contract_addr,
+                entrypoint: OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
+                failure_policy: ListenerFailurePolicy::Ignore,
+            }],
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -1541,6 +13879,7 @@ mod tests {
                 seed_nft_addr: None,
                 sale_addr: None,
                 status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -1548,35 +13887,24 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            listeners: vec![Listener {
+                address: listener_contract_addr,
+                entrypoint: OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
+                failure_policy: ListenerFailurePolicy::Ignore,
+            }],
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        // set up overlay-users.view_user mock to return curator user so that the curate func would
-        // be called by this func.
-        host.setup_mock_entrypoint(
-            user_contract_addr,
-            OwnedEntrypointName::new_unchecked("view_user".to_string()),
-            MockFn::returning_ok(UserStateResponse {
-                is_curator: true,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
-            }),
-        );
-
-        let params = CurateProjectAdminParams {
-            curator,
-            project_id,
-            project_uri,
-            owners: vec![project_owner1, project_owner2],
+        let params = Listener {
+            address: listener_contract_addr,
+            entrypoint: OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
+            failure_policy: ListenerFailurePolicy::Revert,
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let result: ContractResult<()> = contract_curate_project_admin(&ctx, &mut host);
-        claim!(
-            result.is_ok(),
-            "test_contract_curate_project: Results in rejection."
-        );
+        let result = host.with_rollback(|host| contract_add_listener(&ctx, host));
+        claim_eq!(result, Err(Error::ListenerAlreadyExists));
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -1586,55 +13914,75 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that with_rollback works for the state on invoking
-    /// overlay-projects.contract_validate_project.
-    fn test_contract_validate_project_with_rollback() {
+    /// Test that with_rollback works for the state on invoking overlay-projects.remove_listener.
+    fn test_contract_remove_listener_with_rollback() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
-        let non_validator = AccountAddress([9; 32]);
+        let listener_contract_addr = ContractAddress::new(1002, 0);
 
         let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
         let initial_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: initial_project,
+            ..empty_state(&mut state_builder)
         };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
         let expected_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: expected_project,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        // set up overlay-users.view_user mock to return non-curator user.
-        // this leads to InvalidCaller error.
-        host.setup_mock_entrypoint(
-            user_contract_addr,
-            OwnedEntrypointName::new_unchecked("view_user".to_string()),
-            MockFn::returning_ok(UserStateResponse {
-                is_curator: false,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
-            }),
-        );
-
-        let params = ValidateProjectParams {
-            project_id,
-            owners: vec![project_owner1, project_owner2],
-            token_addr: None,
+        let params = RemoveListenerParams {
+            address: listener_contract_addr,
+            entrypoint: OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        ctx.set_sender(Address::Account(non_validator));
-        let _ = host.with_rollback(|host| contract_validate_project(&ctx, host));
+        let result = host.with_rollback(|host| contract_remove_listener(&ctx, host));
+        claim_eq!(result, Err(Error::ListenerNotFound));
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -1642,21 +13990,24 @@ mod tests {
             "state has been changed unexpectedly..."
         );
     }
-
-    #[concordium_test]
-    /// Test that overlay-projects.validate_project successfully invoke overlay-users.validate
-    /// function.
-    fn test_contract_validate_project() {
+
+    #[concordium_test]
+    /// Test that overlay-projects.remove_listener removes the inputted listener.
+    fn test_contract_remove_listener() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJK".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
-        let project_owner1 = AccountAddress([5; 32]);
-        let project_owner2 = AccountAddress([6; 32]);
-        let validator = AccountAddress([3; 32]);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let listener_contract_addr = ContractAddress::new(1002, 0);
 
         let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
         let mut state_builder = TestStateBuilder::new();
         let mut initial_project = state_builder.new_map();
         initial_project.insert(
@@ -1669,6 +14020,7 @@ mod tests {
                 seed_nft_addr: None,
                 sale_addr: None,
                 status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let initial_state = State {
@@ -1676,6 +14028,12 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: initial_project,
+            listeners: vec![Listener {
+                address: listener_contract_addr,
+                entrypoint: OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
+                failure_policy: ListenerFailurePolicy::Ignore,
+            }],
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -1687,7 +14045,8 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Whitelist,
+                status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -1695,40 +14054,20 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        // set up overlay-users.view_user mock to return curator user so that the curate func would
-        // be called by this func.
-        host.setup_mock_entrypoint(
-            user_contract_addr,
-            OwnedEntrypointName::new_unchecked("view_user".to_string()),
-            MockFn::returning_ok(UserStateResponse {
-                is_curator: false,
-                is_validator: true,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
-            }),
-        );
-        // set up overlay-users.curate.
-        host.setup_mock_entrypoint(
-            user_contract_addr,
-            OwnedEntrypointName::new_unchecked("validate".to_string()),
-            MockFn::returning_ok(()),
-        );
-
-        let params = ValidateProjectParams {
-            project_id,
-            owners: vec![project_owner1, project_owner2],
-            token_addr: None,
+        let params = RemoveListenerParams {
+            address: listener_contract_addr,
+            entrypoint: OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        ctx.set_sender(Address::Account(validator));
-        let result: ContractResult<()> = contract_validate_project(&ctx, &mut host);
+        let result = contract_remove_listener(&ctx, &mut host);
         claim!(
             result.is_ok(),
-            "test_contract_curate_project: Results in rejection."
+            "test_contract_remove_listener: Results in rejection"
         );
         let actual_state = host.state();
         claim_eq!(
@@ -1739,56 +14078,94 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that with_rollback works for the state on invoking
-    /// overlay-projects.contract_validate_project_admin.
-    fn test_contract_validate_project_admin_with_rollback() {
+    /// Test that overlay-projects.start_sale rejects when a listener with the revert policy fails.
+    fn test_contract_start_sale_with_reverting_listener() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
-        let non_validator = AccountAddress([9; 32]);
+        let listener_contract_addr = ContractAddress::new(1002, 0);
 
         let mut ctx = TestReceiveContext::empty();
         ctx.set_invoker(admin);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        initial_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
         let initial_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: initial_project,
+            listeners: vec![Listener {
+                address: listener_contract_addr,
+                entrypoint: OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
+                failure_policy: ListenerFailurePolicy::Revert,
+            }],
+            ..empty_state(&mut state_builder)
         };
+        let mut expected_project = state_builder.new_map();
+        expected_project.insert(
+            project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
+            },
+        );
         let expected_state = State {
             admin,
             staking_contract_addr,
             user_contract_addr,
-            project: state_builder.new_map(),
+            project: expected_project,
+            listeners: vec![Listener {
+                address: listener_contract_addr,
+                entrypoint: OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
+                failure_policy: ListenerFailurePolicy::Revert,
+            }],
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        // set up overlay-users.view_user mock to return non-curator user.
-        // this leads to InvalidCaller error.
         host.setup_mock_entrypoint(
             user_contract_addr,
-            OwnedEntrypointName::new_unchecked("view_user".to_string()),
-            MockFn::returning_ok(UserStateResponse {
-                is_curator: false,
-                is_validator: false,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
-            }),
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            MockFn::returning_ok(()),
+        );
+        // set up a listener contract which fails on being notified.
+        host.setup_mock_entrypoint(
+            listener_contract_addr,
+            OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
+            MockFn::returning_err::<()>(CallContractError::Trap),
         );
 
-        let params = ValidateProjectAdminParams {
-            validator: non_validator,
-            project_id,
-            owners: vec![project_owner1, project_owner2],
-            token_addr: None,
-        };
+        let params = StartSaleParams { project_id };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_validate_project_admin(&ctx, host));
+        let result = host.with_rollback(|host| contract_start_sale(&ctx, host));
+        claim_eq!(result, Err(Error::FailedInvokeListener));
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -1798,16 +14175,19 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that overlay-projects.validate_project_admin successfully finish its process.
-    fn test_contract_validate_project_admin() {
+    /// Test that overlay-projects.start_sale goes through when a listener with the ignore policy fails.
+    fn test_contract_start_sale_with_ignoring_listener() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJK".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
-        let project_owner1 = AccountAddress([5; 32]);
-        let project_owner2 = AccountAddress([6; 32]);
-        let validator = AccountAddress([3; 32]);
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+        let project_owner2 = AccountAddress([8; 32]);
+        let listener_contract_addr = ContractAddress::new(1002, 0);
 
         let mut ctx = TestReceiveContext::empty();
         ctx.set_invoker(admin);
@@ -1822,7 +14202,8 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Candidate,
+                status: ProjectStatus::Whitelist,
+                ..default_project()
             },
         );
         let initial_state = State {
@@ -1830,6 +14211,12 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: initial_project,
+            listeners: vec![Listener {
+                address: listener_contract_addr,
+                entrypoint: OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
+                failure_policy: ListenerFailurePolicy::Ignore,
+            }],
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -1841,7 +14228,8 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Whitelist,
+                status: ProjectStatus::OnSale,
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -1849,34 +14237,34 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            listeners: vec![Listener {
+                address: listener_contract_addr,
+                entrypoint: OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
+                failure_policy: ListenerFailurePolicy::Ignore,
+            }],
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        // set up overlay-users.view_user mock to return curator user so that the curate func would
-        // be called by this func.
         host.setup_mock_entrypoint(
             user_contract_addr,
-            OwnedEntrypointName::new_unchecked("view_user".to_string()),
-            MockFn::returning_ok(UserStateResponse {
-                is_curator: false,
-                is_validator: true,
-                curated_projects: Vec::new(),
-                validated_projects: Vec::new(),
-            }),
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            MockFn::returning_ok(()),
+        );
+        // set up a listener contract which fails on being notified.
+        host.setup_mock_entrypoint(
+            listener_contract_addr,
+            OwnedEntrypointName::new_unchecked("on_project_status".to_string()),
+            MockFn::returning_err::<()>(CallContractError::Trap),
         );
 
-        let params = ValidateProjectAdminParams {
-            validator,
-            project_id,
-            owners: vec![project_owner1, project_owner2],
-            token_addr: None,
-        };
+        let params = StartSaleParams { project_id };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let result: ContractResult<()> = contract_validate_project_admin(&ctx, &mut host);
+        let result = contract_start_sale(&ctx, &mut host);
         claim!(
             result.is_ok(),
-            "test_contract_curate_project: Results in rejection."
+            "test_contract_start_sale_with_ignoring_listener: Results in rejection"
         );
         let actual_state = host.state();
         claim_eq!(
@@ -1887,14 +14275,140 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that with_rollback works for the state on invoking overlay-projects.add_token_addr.
-    fn test_contract_add_token_addr_with_rollback() {
+    /// Test that the listener contracts are notified of the creation, archive, deletion and merge
+    /// of projects with the kind of the change.
+    fn test_contract_notify_listeners_of_project_changes() {
+        let admin = AccountAddress([1; 32]);
+        let user_contract_addr = ContractAddress::new(1001, 0);
+        let listener_contract_addr = ContractAddress::new(1002, 0);
+        let listener_entrypoint = OwnedEntrypointName::new_unchecked("on_project".to_string());
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
+        let project_owner1 = AccountAddress([7; 32]);
+
+        let mut state_builder = TestStateBuilder::new();
+        let mut initial_project = state_builder.new_map();
+        for project_id in ["archived", "deleted", "kept", "dropped"] {
+            initial_project.insert(
+                project_id.into(),
+                ProjectState {
+                    project_uri: None,
+                    owners: vec![project_owner1],
+                    pub_key: None,
+                    token_addr: None,
+                    seed_nft_addr: None,
+                    sale_addr: None,
+                    status: ProjectStatus::Candidate,
+                    ..default_project()
+                },
+            );
+        }
+        let initial_state = State {
+            admin,
+            staking_contract_addr: ContractAddress::new(1000, 0),
+            user_contract_addr,
+            project: initial_project,
+            listeners: vec![Listener {
+                address: listener_contract_addr,
+                entrypoint: listener_entrypoint.clone(),
+                failure_policy: ListenerFailurePolicy::Revert,
+            }],
+            ..empty_state(&mut state_builder)
+        };
+        let mut host = TestHost::new(initial_state, state_builder);
+        for entrypoint in ["update_project_status", "remove_project", "merge_project"] {
+            host.setup_mock_entrypoint(
+                user_contract_addr,
+                OwnedEntrypointName::new_unchecked(entrypoint.to_string()),
+                MockFn::returning_ok(()),
+            );
+        }
+
+        let mut ctx = TestReceiveContext::empty();
+        ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+
+        host.setup_mock_entrypoint(
+            listener_contract_addr,
+            listener_entrypoint.clone(),
+            mock_listener(ProjectNotification {
+                project_id: "created".into(),
+                status: ProjectStatus::Candidate,
+                event: ProjectEvent::Created,
+            }),
+        );
+        let params_byte = to_bytes(&ApplyCurateProjectParams {
+            project_id: Some("created".into()),
+            project_uri,
+            owners: vec![project_owner1],
+        });
+        ctx.set_parameter(&params_byte);
+        let result = contract_apply_curate_project(&ctx, &mut host);
+        claim_eq!(result, Ok("created".into()));
+
+        host.setup_mock_entrypoint(
+            listener_contract_addr,
+            listener_entrypoint.clone(),
+            mock_listener(ProjectNotification {
+                project_id: "archived".into(),
+                status: ProjectStatus::Candidate,
+                event: ProjectEvent::Archived,
+            }),
+        );
+        let params_byte = to_bytes(&ArchiveProjectParams {
+            project_id: "archived".into(),
+        });
+        ctx.set_parameter(&params_byte);
+        let result = contract_archive_project(&ctx, &mut host);
+        claim_eq!(result, Ok(()));
+
+        host.setup_mock_entrypoint(
+            listener_contract_addr,
+            listener_entrypoint.clone(),
+            mock_listener(ProjectNotification {
+                project_id: "deleted".into(),
+                status: ProjectStatus::Candidate,
+                event: ProjectEvent::Deleted,
+            }),
+        );
+        let params_byte = to_bytes(&DeleteProjectParams {
+            project_id: "deleted".into(),
+        });
+        ctx.set_parameter(&params_byte);
+        let result = contract_delete_project(&ctx, &mut host);
+        claim_eq!(result, Ok(()));
+
+        host.setup_mock_entrypoint(
+            listener_contract_addr,
+            listener_entrypoint,
+            mock_listener(ProjectNotification {
+                project_id: "dropped".into(),
+                status: ProjectStatus::Candidate,
+                event: ProjectEvent::Merged("kept".into()),
+            }),
+        );
+        let params_byte = to_bytes(&MergeProjectsParams {
+            keep: "kept".into(),
+            drop: "dropped".into(),
+        });
+        ctx.set_parameter(&params_byte);
+        let result = contract_merge_projects(&ctx, &mut host);
+        claim_eq!(result, Ok(()));
+    }
+
+    #[concordium_test]
+    /// Test that with_rollback works for the state on invoking overlay-projects.set_reward_points.
+    fn test_contract_set_reward_points_with_rollback() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let token_addr = ContractAddress::new(1002, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
 
@@ -1911,7 +14425,8 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Whitelist,
+                status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let initial_state = State {
@@ -1919,6 +14434,7 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: initial_project,
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -1930,7 +14446,8 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Whitelist,
+                status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -1938,16 +14455,18 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = AddTokenAddrParams {
-            project_id,
-            token_addr,
+        let params = RewardPoints {
+            whitelist: 5,
+            sale_closed: 50,
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_add_token_addr(&ctx, host));
+        let result = host.with_rollback(|host| contract_set_reward_points(&ctx, host));
+        claim_eq!(result, Err(Error::InvalidCaller));
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -1957,19 +14476,21 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that overlay-projects.add_token_addr successfully update project's token address.
-    fn test_contract_add_token_addr() {
+    /// Test that overlay-projects.set_reward_points updates reward points.
+    fn test_contract_set_reward_points() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let token_addr = ContractAddress::new(1002, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
 
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(project_owner1);
+        ctx.set_invoker(admin);
         let mut state_builder = TestStateBuilder::new();
         let mut initial_project = state_builder.new_map();
         initial_project.insert(
@@ -1982,6 +14503,7 @@ mod tests {
                 seed_nft_addr: None,
                 sale_addr: None,
                 status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let initial_state = State {
@@ -1989,6 +14511,7 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: initial_project,
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -1997,10 +14520,11 @@ mod tests {
                 project_uri: Some(project_uri.clone()),
                 owners: vec![project_owner1, project_owner2],
                 pub_key: None,
-                token_addr: Some(token_addr),
+                token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
                 status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -2008,16 +14532,25 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            reward_points: RewardPoints {
+                whitelist: 5,
+                sale_closed: 50,
+            },
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = AddTokenAddrParams {
-            project_id,
-            token_addr,
+        let params = RewardPoints {
+            whitelist: 5,
+            sale_closed: 50,
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_add_token_addr(&ctx, host));
+        let result = contract_set_reward_points(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_set_reward_points: Results in rejection"
+        );
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -2027,20 +14560,41 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that with_rollback works for the state on invoking overlay-projects.add_pub_key.
-    fn test_contract_add_pub_key_with_rollback() {
+    /// Test that overlay-projects.close_sale neither counts the project as sold out in the stats of
+    /// its curator and validator nor accrues reward points while the sale can be refunded.
+    fn test_contract_close_sale_defers_rewards() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let pub_key: PublicKey = "test-pub-key".into();
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
+        let curator = AccountAddress([3; 32]);
+        let validator = AccountAddress([4; 32]);
 
         let mut ctx = TestReceiveContext::empty();
         ctx.set_invoker(admin);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_rewards = state_builder.new_map();
+        initial_rewards.insert(
+            curator,
+            RewardState {
+                pending: DEFAULT_WHITELIST_REWARD_POINTS,
+                claimed: 0,
+            },
+        );
+        let mut expected_rewards = state_builder.new_map();
+        expected_rewards.insert(
+            curator,
+            RewardState {
+                pending: DEFAULT_WHITELIST_REWARD_POINTS,
+                claimed: 0,
+            },
+        );
         let mut initial_project = state_builder.new_map();
         initial_project.insert(
             project_id.clone(),
@@ -2051,7 +14605,10 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Candidate,
+                status: ProjectStatus::OnSale,
+                curated_by: Some(curator),
+                validated_by: Some(validator),
+                ..default_project()
             },
         );
         let initial_state = State {
@@ -2059,6 +14616,8 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: initial_project,
+            rewards: initial_rewards,
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -2070,7 +14629,10 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Candidate,
+                status: ProjectStatus::SaleClosed,
+                curated_by: Some(curator),
+                validated_by: Some(validator),
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -2078,16 +14640,25 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            rewards: expected_rewards,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = AddPubKeyParams {
-            project_id,
-            pub_key,
-        };
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            MockFn::returning_ok(()),
+        );
+
+        let params = CloseSaleParams { project_id };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_add_pub_key(&ctx, host));
+        let result = contract_close_sale(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_close_sale_defers_rewards: Results in rejection"
+        );
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -2097,20 +14668,51 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that overlay-projects.add_pub_key successfully update project's pub_key
-    fn test_contract_add_pub_key() {
+    /// Test that overlay-projects.generate_token accrues sale closed reward points to the curator and
+    /// the validator of the project and counts it as sold out in their stats, since the sale can no
+    /// longer be refunded.
+    fn test_contract_generate_token_accrues_rewards() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let pub_key: PublicKey = "test-pub-key".into();
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
+        let curator = AccountAddress([3; 32]);
+        let validator = AccountAddress([4; 32]);
+        let token_addr = ContractAddress::new(2000, 0);
 
         let mut ctx = TestReceiveContext::empty();
         ctx.set_invoker(admin);
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_rewards = state_builder.new_map();
+        initial_rewards.insert(
+            curator,
+            RewardState {
+                pending: DEFAULT_WHITELIST_REWARD_POINTS,
+                claimed: 0,
+            },
+        );
+        let mut expected_rewards = state_builder.new_map();
+        expected_rewards.insert(
+            curator,
+            RewardState {
+                pending: DEFAULT_WHITELIST_REWARD_POINTS + DEFAULT_SALE_CLOSED_REWARD_POINTS,
+                claimed: 0,
+            },
+        );
+        expected_rewards.insert(
+            validator,
+            RewardState {
+                pending: DEFAULT_SALE_CLOSED_REWARD_POINTS,
+                claimed: 0,
+            },
+        );
         let mut initial_project = state_builder.new_map();
         initial_project.insert(
             project_id.clone(),
@@ -2121,7 +14723,10 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Whitelist,
+                status: ProjectStatus::SaleClosed,
+                curated_by: Some(curator),
+                validated_by: Some(validator),
+                ..default_project()
             },
         );
         let initial_state = State {
@@ -2129,6 +14734,8 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: initial_project,
+            rewards: initial_rewards,
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -2136,11 +14743,35 @@ mod tests {
             ProjectState {
                 project_uri: Some(project_uri.clone()),
                 owners: vec![project_owner1, project_owner2],
-                pub_key: Some(pub_key.clone()),
-                token_addr: None,
+                pub_key: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Whitelist,
+                status: ProjectStatus::TokenGenerated,
+                token_addr: Some(token_addr),
+                token_generated_at: Some(Timestamp::from_timestamp_millis(100)),
+                curated_by: Some(curator),
+                validated_by: Some(validator),
+                ..default_project()
+            },
+        );
+        let mut expected_curator_stats = state_builder.new_map();
+        expected_curator_stats.insert(
+            curator,
+            ProjectStats {
+                projects: 0,
+                whitelisted: 0,
+                sold_out: 1,
+                rejected: 0,
+            },
+        );
+        let mut expected_validator_stats = state_builder.new_map();
+        expected_validator_stats.insert(
+            validator,
+            ProjectStats {
+                projects: 0,
+                whitelisted: 0,
+                sold_out: 1,
+                rejected: 0,
             },
         );
         let expected_state = State {
@@ -2148,16 +14779,30 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            rewards: expected_rewards,
+            curator_stats: expected_curator_stats,
+            validator_stats: expected_validator_stats,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = AddPubKeyParams {
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            MockFn::returning_ok(()),
+        );
+
+        let params = GenerateTokenParams {
             project_id,
-            pub_key,
+            token_addr,
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_add_pub_key(&ctx, host));
+        let result = contract_generate_token(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_generate_token_accrues_rewards: Results in rejection"
+        );
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -2167,21 +14812,39 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that with_rollback works for the state on invoking overlay-projects.update_owners.
-    fn test_contract_update_owners_with_rollback() {
+    /// Test that with_rollback works for the state on invoking overlay-projects.claim_rewards.
+    fn test_contract_claim_rewards_with_rollback() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
-        let new_project_owner1 = AccountAddress([9; 32]);
-        let new_project_owner2 = AccountAddress([10; 32]);
+        let curator = AccountAddress([3; 32]);
 
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(admin);
+        ctx.set_invoker(curator);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_rewards = state_builder.new_map();
+        initial_rewards.insert(
+            curator,
+            RewardState {
+                pending: 0,
+                claimed: 30,
+            },
+        );
+        let mut expected_rewards = state_builder.new_map();
+        expected_rewards.insert(
+            curator,
+            RewardState {
+                pending: 0,
+                claimed: 30,
+            },
+        );
         let mut initial_project = state_builder.new_map();
         initial_project.insert(
             project_id.clone(),
@@ -2193,6 +14856,7 @@ mod tests {
                 seed_nft_addr: None,
                 sale_addr: None,
                 status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let initial_state = State {
@@ -2200,6 +14864,8 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: initial_project,
+            rewards: initial_rewards,
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -2212,6 +14878,7 @@ mod tests {
                 seed_nft_addr: None,
                 sale_addr: None,
                 status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -2219,16 +14886,16 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            rewards: expected_rewards,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = UpdateOwnersParams {
-            project_id,
-            owners: vec![new_project_owner1, new_project_owner2],
-        };
-        let params_byte = to_bytes(&params);
-        ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_update_owners(&ctx, host));
+        ctx.set_sender(Address::Account(curator));
+        let mut logger = TestLogger::init();
+        let result = host.with_rollback(|host| contract_claim_rewards(&ctx, host, &mut logger));
+        claim_eq!(result, Err(Error::NoPendingRewards));
+        claim!(logger.logs.is_empty(), "Unexpected event has been logged.");
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -2238,21 +14905,55 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that overlay-projects.update_owners successfully update project's pub_key
-    fn test_contract_update_owners() {
+    /// Test that overlay-projects.claim_rewards moves pending reward points of the caller to claimed
+    /// and logs the claim.
+    fn test_contract_claim_rewards() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
-        let new_project_owner1 = AccountAddress([9; 32]);
-        let new_project_owner2 = AccountAddress([10; 32]);
+        let curator = AccountAddress([3; 32]);
+        let validator = AccountAddress([4; 32]);
 
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(admin);
+        ctx.set_invoker(curator);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_rewards = state_builder.new_map();
+        initial_rewards.insert(
+            curator,
+            RewardState {
+                pending: 30,
+                claimed: 10,
+            },
+        );
+        initial_rewards.insert(
+            validator,
+            RewardState {
+                pending: 20,
+                claimed: 0,
+            },
+        );
+        let mut expected_rewards = state_builder.new_map();
+        expected_rewards.insert(
+            curator,
+            RewardState {
+                pending: 0,
+                claimed: 40,
+            },
+        );
+        expected_rewards.insert(
+            validator,
+            RewardState {
+                pending: 20,
+                claimed: 0,
+            },
+        );
         let mut initial_project = state_builder.new_map();
         initial_project.insert(
             project_id.clone(),
@@ -2263,7 +14964,8 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Whitelist,
+                status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let initial_state = State {
@@ -2271,18 +14973,21 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: initial_project,
+            rewards: initial_rewards,
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
             project_id.clone(),
             ProjectState {
                 project_uri: Some(project_uri.clone()),
-                owners: vec![new_project_owner1, new_project_owner2],
+                owners: vec![project_owner1, project_owner2],
                 pub_key: None,
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Whitelist,
+                status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -2290,16 +14995,23 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            rewards: expected_rewards,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = UpdateOwnersParams {
-            project_id,
-            owners: vec![new_project_owner1, new_project_owner2],
-        };
-        let params_byte = to_bytes(&params);
-        ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_update_owners(&ctx, host));
+        ctx.set_sender(Address::Account(curator));
+        let mut logger = TestLogger::init();
+        let result = contract_claim_rewards(&ctx, &mut host, &mut logger);
+        claim_eq!(result, Ok(30));
+        claim_eq!(
+            logger.logs,
+            vec![to_bytes(&RewardsClaimedEvent {
+                account: curator,
+                points: 30,
+            })],
+            "RewardsClaimed event has not been logged."
+        );
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -2309,20 +15021,66 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that with_rollback works for the state on invoking overlay-projects.add_seed_sale.
-    fn test_contract_add_seed_sale_with_rollback() {
+    /// Test that overlay-projects.complete_refund counts the cancelled project as rejected in the stats
+    /// of its curator and validator.
+    fn test_contract_complete_refund_updates_stats() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let seed_nft_addr = ContractAddress::new(1002, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
+        let sale_addr = ContractAddress::new(2003, 0);
+        let curator = AccountAddress([3; 32]);
+        let validator = AccountAddress([4; 32]);
 
         let mut ctx = TestReceiveContext::empty();
         ctx.set_invoker(admin);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_curator_stats = state_builder.new_map();
+        initial_curator_stats.insert(
+            curator,
+            ProjectStats {
+                projects: 2,
+                whitelisted: 1,
+                sold_out: 0,
+                rejected: 0,
+            },
+        );
+        let mut initial_validator_stats = state_builder.new_map();
+        initial_validator_stats.insert(
+            validator,
+            ProjectStats {
+                projects: 1,
+                whitelisted: 1,
+                sold_out: 0,
+                rejected: 0,
+            },
+        );
+        let mut expected_curator_stats = state_builder.new_map();
+        expected_curator_stats.insert(
+            curator,
+            ProjectStats {
+                projects: 2,
+                whitelisted: 1,
+                sold_out: 0,
+                rejected: 1,
+            },
+        );
+        let mut expected_validator_stats = state_builder.new_map();
+        expected_validator_stats.insert(
+            validator,
+            ProjectStats {
+                projects: 1,
+                whitelisted: 1,
+                sold_out: 0,
+                rejected: 1,
+            },
+        );
         let mut initial_project = state_builder.new_map();
         initial_project.insert(
             project_id.clone(),
@@ -2332,8 +15090,11 @@ mod tests {
                 pub_key: None,
                 token_addr: None,
                 seed_nft_addr: None,
-                sale_addr: None,
-                status: ProjectStatus::Candidate,
+                sale_addr: Some(sale_addr),
+                status: ProjectStatus::Refunding,
+                curated_by: Some(curator),
+                validated_by: Some(validator),
+                ..default_project()
             },
         );
         let initial_state = State {
@@ -2341,6 +15102,9 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: initial_project,
+            curator_stats: initial_curator_stats,
+            validator_stats: initial_validator_stats,
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -2351,8 +15115,11 @@ mod tests {
                 pub_key: None,
                 token_addr: None,
                 seed_nft_addr: None,
-                sale_addr: None,
-                status: ProjectStatus::Candidate,
+                sale_addr: Some(sale_addr),
+                status: ProjectStatus::Cancelled,
+                curated_by: Some(curator),
+                validated_by: Some(validator),
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -2360,16 +15127,27 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            curator_stats: expected_curator_stats,
+            validator_stats: expected_validator_stats,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = AddSeedSaleParams {
-            project_id,
-            seed_nft_addr,
-        };
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            MockFn::returning_ok(()),
+        );
+
+        ctx.set_sender(Address::Contract(sale_addr));
+        let params = CompleteRefundParams { project_id };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_add_seed_sale(&ctx, host));
+        let result = contract_complete_refund(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_complete_refund_updates_stats: Results in rejection"
+        );
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -2379,20 +15157,42 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that overlay-projects.add_seed_sale successfully update project's seed NFT address.
-    fn test_contract_add_seed_sale() {
+    /// Test that overlay-projects.curate_project rejects the curator who has max open Candidate projects.
+    fn test_contract_curate_project_exceeding_open_candidates() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let seed_nft_addr = ContractAddress::new(1002, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
+        let curator = AccountAddress([3; 32]);
+        let new_project_id: ProjectId = "dlsfjj-x87877xjjo".into();
 
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(admin);
+        ctx.set_invoker(curator);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_curator_quotas = state_builder.new_map();
+        initial_curator_quotas.insert(
+            curator,
+            CuratorQuota {
+                open_candidates: DEFAULT_MAX_OPEN_CANDIDATES,
+                window_start: Timestamp::from_timestamp_millis(0),
+                window_curations: 0,
+            },
+        );
+        let mut expected_curator_quotas = state_builder.new_map();
+        expected_curator_quotas.insert(
+            curator,
+            CuratorQuota {
+                open_candidates: DEFAULT_MAX_OPEN_CANDIDATES,
+                window_start: Timestamp::from_timestamp_millis(0),
+                window_curations: 0,
+            },
+        );
         let mut initial_project = state_builder.new_map();
         initial_project.insert(
             project_id.clone(),
@@ -2403,7 +15203,8 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Whitelist,
+                status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let initial_state = State {
@@ -2411,6 +15212,8 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: initial_project,
+            curator_quotas: initial_curator_quotas,
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -2420,9 +15223,10 @@ mod tests {
                 owners: vec![project_owner1, project_owner2],
                 pub_key: None,
                 token_addr: None,
-                seed_nft_addr: Some(seed_nft_addr),
+                seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Whitelist,
+                status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -2430,16 +15234,38 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            curator_quotas: expected_curator_quotas,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = AddSeedSaleParams {
-            project_id,
-            seed_nft_addr,
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("curate".to_string()),
+            MockFn::returning_ok(()),
+        );
+
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+        ctx.set_sender(Address::Account(curator));
+        let params = CurateProjectParams {
+            project_id: Some(new_project_id.clone()),
+            project_uri: project_uri.clone(),
+            owners: vec![project_owner1, project_owner2],
         };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_add_seed_sale(&ctx, host));
+        let result = host.with_rollback(|host| contract_curate_project(&ctx, host));
+        claim_eq!(result, Err(Error::CurationLimitExceeded));
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -2449,19 +15275,43 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that with_rollback works for the state on invoking overlay-projects.start_sale.
-    fn test_contract_start_sale_with_rollback() {
+    /// Test that overlay-projects.curate_project rejects the curator who has curated max projects in
+    /// the current window.
+    fn test_contract_curate_project_exceeding_curations_per_window() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
+        let curator = AccountAddress([3; 32]);
+        let new_project_id: ProjectId = "dlsfjj-x87877xjjo".into();
 
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(admin);
+        ctx.set_invoker(curator);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_curator_quotas = state_builder.new_map();
+        initial_curator_quotas.insert(
+            curator,
+            CuratorQuota {
+                open_candidates: 0,
+                window_start: Timestamp::from_timestamp_millis(0),
+                window_curations: DEFAULT_MAX_CURATIONS_PER_WINDOW,
+            },
+        );
+        let mut expected_curator_quotas = state_builder.new_map();
+        expected_curator_quotas.insert(
+            curator,
+            CuratorQuota {
+                open_candidates: 0,
+                window_start: Timestamp::from_timestamp_millis(0),
+                window_curations: DEFAULT_MAX_CURATIONS_PER_WINDOW,
+            },
+        );
         let mut initial_project = state_builder.new_map();
         initial_project.insert(
             project_id.clone(),
@@ -2473,6 +15323,7 @@ mod tests {
                 seed_nft_addr: None,
                 sale_addr: None,
                 status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let initial_state = State {
@@ -2480,6 +15331,8 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: initial_project,
+            curator_quotas: initial_curator_quotas,
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -2492,6 +15345,7 @@ mod tests {
                 seed_nft_addr: None,
                 sale_addr: None,
                 status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -2499,13 +15353,38 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            curator_quotas: expected_curator_quotas,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = StartSaleParams { project_id };
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("curate".to_string()),
+            MockFn::returning_ok(()),
+        );
+
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(100));
+        ctx.set_sender(Address::Account(curator));
+        let params = CurateProjectParams {
+            project_id: Some(new_project_id.clone()),
+            project_uri: project_uri.clone(),
+            owners: vec![project_owner1, project_owner2],
+        };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_start_sale(&ctx, host));
+        let result = host.with_rollback(|host| contract_curate_project(&ctx, host));
+        claim_eq!(result, Err(Error::CurationLimitExceeded));
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -2515,19 +15394,61 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that overlay-projects.start_sale successfully update project's status as "on sale".
-    fn test_contract_start_sale() {
+    /// Test that overlay-projects.curate_project starts a new window once the current one has expired.
+    fn test_contract_curate_project_after_window_expired() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
+        let curator = AccountAddress([3; 32]);
+        let new_project_id: ProjectId = "dlsfjj-x87877xjjo".into();
 
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(admin);
+        ctx.set_invoker(curator);
         let mut state_builder = TestStateBuilder::new();
+        let mut initial_curator_quotas = state_builder.new_map();
+        initial_curator_quotas.insert(
+            curator,
+            CuratorQuota {
+                open_candidates: 1,
+                window_start: Timestamp::from_timestamp_millis(0),
+                window_curations: DEFAULT_MAX_CURATIONS_PER_WINDOW,
+            },
+        );
+        let mut expected_curator_quotas = state_builder.new_map();
+        expected_curator_quotas.insert(
+            curator,
+            CuratorQuota {
+                open_candidates: 2,
+                window_start: Timestamp::from_timestamp_millis(DEFAULT_CURATION_WINDOW_MILLIS),
+                window_curations: 1,
+            },
+        );
+        let mut expected_metadata_history = state_builder.new_map();
+        expected_metadata_history.insert(
+            new_project_id.clone(),
+            vec![MetadataRecord {
+                uri: project_uri.clone(),
+                timestamp: Timestamp::from_timestamp_millis(DEFAULT_CURATION_WINDOW_MILLIS),
+                changed_by: curator,
+            }],
+        );
+        let mut expected_curator_stats = state_builder.new_map();
+        expected_curator_stats.insert(
+            curator,
+            ProjectStats {
+                projects: 1,
+                whitelisted: 0,
+                sold_out: 0,
+                rejected: 0,
+            },
+        );
         let mut initial_project = state_builder.new_map();
         initial_project.insert(
             project_id.clone(),
@@ -2538,7 +15459,8 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Whitelist,
+                status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let initial_state = State {
@@ -2546,6 +15468,8 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: initial_project,
+            curator_quotas: initial_curator_quotas,
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -2557,7 +15481,23 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::OnSale,
+                status: ProjectStatus::Candidate,
+                ..default_project()
+            },
+        );
+        expected_project.insert(
+            new_project_id.clone(),
+            ProjectState {
+                project_uri: Some(project_uri.clone()),
+                owners: vec![project_owner1, project_owner2],
+                pub_key: None,
+                token_addr: None,
+                seed_nft_addr: None,
+                sale_addr: None,
+                status: ProjectStatus::Candidate,
+                owner_threshold: 2,
+                curated_by: Some(curator),
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -2565,13 +15505,51 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            metadata_history: expected_metadata_history,
+            curator_stats: expected_curator_stats,
+            curator_quotas: expected_curator_quotas,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = StartSaleParams { project_id };
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("view_user".to_string()),
+            MockFn::returning_ok(UserStateResponse {
+                is_curator: true,
+                is_validator: false,
+                curated_projects: Vec::new(),
+                validated_projects: Vec::new(),
+            }),
+        );
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("curate".to_string()),
+            MockFn::returning_ok(()),
+        );
+        // set up overlay-users.update_project_status.
+        host.setup_mock_entrypoint(
+            user_contract_addr,
+            OwnedEntrypointName::new_unchecked("update_project_status".to_string()),
+            mock_update_project_status(new_project_id.clone(), ProjectStatus::Candidate),
+        );
+
+        ctx.set_metadata_slot_time(Timestamp::from_timestamp_millis(
+            DEFAULT_CURATION_WINDOW_MILLIS,
+        ));
+        ctx.set_sender(Address::Account(curator));
+        let params = CurateProjectParams {
+            project_id: Some(new_project_id.clone()),
+            project_uri: project_uri.clone(),
+            owners: vec![project_owner1, project_owner2],
+        };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_start_sale(&ctx, host));
+        let result = contract_curate_project(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_curate_project_after_window_expired: Results in rejection"
+        );
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -2581,18 +15559,21 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that with_rollback works for the state on invoking overlay-projects.close_sale.
-    fn test_contract_close_sale_with_rollback() {
+    /// Test that with_rollback works for the state on invoking overlay-projects.set_curation_limits.
+    fn test_contract_set_curation_limits_with_rollback() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
 
         let mut ctx = TestReceiveContext::empty();
-        ctx.set_invoker(admin);
+        ctx.set_invoker(project_owner1);
         let mut state_builder = TestStateBuilder::new();
         let mut initial_project = state_builder.new_map();
         initial_project.insert(
@@ -2604,7 +15585,8 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Whitelist,
+                status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let initial_state = State {
@@ -2612,6 +15594,7 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: initial_project,
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -2623,7 +15606,8 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::Whitelist,
+                status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -2631,13 +15615,19 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = CloseSaleParams { project_id };
+        let params = CurationLimits {
+            max_open_candidates: 10,
+            max_curations_per_window: 5,
+            window: Duration::from_millis(60 * 60 * 1000),
+        };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_close_sale(&ctx, host));
+        let result = host.with_rollback(|host| contract_set_curation_limits(&ctx, host));
+        claim_eq!(result, Err(Error::InvalidCaller));
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
@@ -2647,13 +15637,16 @@ mod tests {
     }
 
     #[concordium_test]
-    /// Test that overlay-projects.close_sale successfully update project's status as "sale closed".
-    fn test_contract_close_sale() {
+    /// Test that overlay-projects.set_curation_limits updates curation limits.
+    fn test_contract_set_curation_limits() {
         let admin = AccountAddress([1; 32]);
         let staking_contract_addr = ContractAddress::new(1000, 0);
         let user_contract_addr = ContractAddress::new(1001, 0);
-        let project_id: ProjectId = "DLSFJJ&&X87877XJJN".into();
-        let project_uri: ProjectUri = "https://overlay.global/".into();
+        let project_id: ProjectId = "dlsfjj-x87877xjjn".into();
+        let project_uri = ProjectUri {
+            url: "https://overlay.global/".into(),
+            hash: Some([0; 32]),
+        };
         let project_owner1 = AccountAddress([7; 32]);
         let project_owner2 = AccountAddress([8; 32]);
 
@@ -2670,7 +15663,8 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::OnSale,
+                status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let initial_state = State {
@@ -2678,6 +15672,7 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: initial_project,
+            ..empty_state(&mut state_builder)
         };
         let mut expected_project = state_builder.new_map();
         expected_project.insert(
@@ -2689,7 +15684,8 @@ mod tests {
                 token_addr: None,
                 seed_nft_addr: None,
                 sale_addr: None,
-                status: ProjectStatus::SaleClosed,
+                status: ProjectStatus::Candidate,
+                ..default_project()
             },
         );
         let expected_state = State {
@@ -2697,13 +15693,27 @@ mod tests {
             staking_contract_addr,
             user_contract_addr,
             project: expected_project,
+            curation_limits: CurationLimits {
+                max_open_candidates: 10,
+                max_curations_per_window: 5,
+                window: Duration::from_millis(60 * 60 * 1000),
+            },
+            ..empty_state(&mut state_builder)
         };
         let mut host = TestHost::new(initial_state, state_builder);
 
-        let params = CloseSaleParams { project_id };
+        let params = CurationLimits {
+            max_open_candidates: 10,
+            max_curations_per_window: 5,
+            window: Duration::from_millis(60 * 60 * 1000),
+        };
         let params_byte = to_bytes(&params);
         ctx.set_parameter(&params_byte);
-        let _ = host.with_rollback(|host| contract_close_sale(&ctx, host));
+        let result = contract_set_curation_limits(&ctx, &mut host);
+        claim!(
+            result.is_ok(),
+            "test_contract_set_curation_limits: Results in rejection"
+        );
         let actual_state = host.state();
         claim_eq!(
             *actual_state,
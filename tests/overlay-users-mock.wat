@@ -0,0 +1,17 @@
+;; Source of overlay-users-mock.wasm, a stand-in for the overlay-users contract in integration
+;; tests. `view_user` reports every account as a curator without any projects, and the other
+;; entrypoints accept any parameter.
+(module
+  (import "concordium" "write_output" (func $write_output (param i32 i32 i32) (result i32)))
+  (memory 1)
+  ;; UserStateResponse { is_curator: true, is_validator: false, curated_projects: [], validated_projects: [] }
+  (data (i32.const 0) "\01\00\00\00\00\00\00\00\00\00")
+  (func (export "init_overlay-users") (param i64) (result i32)
+    i32.const 0)
+  (func (export "overlay-users.view_user") (param i64) (result i32)
+    (drop (call $write_output (i32.const 0) (i32.const 10) (i32.const 0)))
+    i32.const 0)
+  (func (export "overlay-users.curate") (param i64) (result i32)
+    i32.const 0)
+  (func (export "overlay-users.update_project_status") (param i64) (result i32)
+    i32.const 0))
@@ -0,0 +1,320 @@
+//! Integration tests of overlay-projects.permit, which need account keys and signature checks of
+//! the chain. They run the module built by `cargo concordium test --out
+//! concordium-out/module.wasm.v1`, and are skipped when the module has not been built, e.g. by a
+//! plain `cargo test`.
+use concordium_smart_contract_testing::*;
+use concordium_std::{AccountSignatures, Deserial, Serial};
+use sha2::{Digest, Sha256};
+
+const ADMIN: AccountAddress = AccountAddress([1; 32]);
+const SIGNER: AccountAddress = AccountAddress([7; 32]);
+const SPONSOR: AccountAddress = AccountAddress([9; 32]);
+const PROJECT_ID: &str = "dlsfjj-x87877xjjn";
+const ENERGY: Energy = Energy { energy: 100_000 };
+/// Path of the overlay-projects module built by `cargo concordium test --out`.
+const PROJECTS_MODULE: &str = "concordium-out/module.wasm.v1";
+
+/// Serialized `Error::InvalidSignature` returned on rejection.
+const INVALID_SIGNATURE: u8 = 27;
+
+/// Mirror of `ProjectUri` of the contract.
+#[derive(Serial)]
+struct ProjectUri {
+    url: String,
+    hash: Option<[u8; 32]>,
+}
+
+/// Mirror of the parameter schema for `init` function.
+#[derive(Serial)]
+struct InitParams {
+    staking_contract_addr: ContractAddress,
+    user_contract_addr: ContractAddress,
+}
+
+/// Mirror of the parameter schema for `curate_project_admin` function.
+#[derive(Serial)]
+struct CurateProjectAdminParams {
+    curator: AccountAddress,
+    project_id: Option<String>,
+    project_uri: ProjectUri,
+    owners: Vec<AccountAddress>,
+}
+
+/// Mirror of the parameter schema for `add_token_addr` function.
+#[derive(Serial)]
+struct AddTokenAddrParams {
+    project_id: String,
+    token_addr: ContractAddress,
+}
+
+/// Mirror of the message signed for `permit` function.
+#[derive(Serial, Clone)]
+struct PermitMessage {
+    contract_address: ContractAddress,
+    nonce: u64,
+    timestamp: Timestamp,
+    entry_point: OwnedEntrypointName,
+    #[concordium(size_length = 2)]
+    payload: Vec<u8>,
+}
+
+/// Mirror of the parameter schema for `permit` function.
+#[derive(Serial)]
+struct PermitParams {
+    signature: AccountSignatures,
+    signer: AccountAddress,
+    message: PermitMessage,
+}
+
+/// Mirror of the parameter schema for `nonceOf` function.
+#[derive(Serial)]
+struct NonceOfParams {
+    #[concordium(size_length = 2)]
+    queries: Vec<AccountAddress>,
+}
+
+/// Mirror of the response schema for `nonceOf` function.
+#[derive(Deserial, Debug, PartialEq, Eq)]
+struct NonceOfResponse {
+    #[concordium(size_length = 2)]
+    nonces: Vec<u64>,
+}
+
+/// Mirror of the `Nonce` event logged by `permit` function.
+#[derive(Deserial, Debug, PartialEq, Eq)]
+struct NonceEvent {
+    tag: u8,
+    nonce: u64,
+    sponsoree: AccountAddress,
+}
+
+/// Deploy overlay-users mock and overlay-projects, and curate a project owned by `SIGNER` whose
+/// account keys are `keys`. None if the overlay-projects module has not been built.
+fn setup_chain(keys: &AccountKeys) -> Option<(Chain, ContractAddress)> {
+    if !std::path::Path::new(PROJECTS_MODULE).exists() {
+        eprintln!(
+            "skipping: {} has not been built, run `cargo concordium test --out {}`",
+            PROJECTS_MODULE, PROJECTS_MODULE
+        );
+        return None;
+    }
+    let mut chain = Chain::new_with_time(Timestamp::from_timestamp_millis(100));
+    let balance = Amount::from_ccd(10_000);
+    chain.create_account(Account::new(ADMIN, balance));
+    chain.create_account(Account::new(SPONSOR, balance));
+    chain.create_account(Account::new_with_keys(
+        SIGNER,
+        AccountBalance::new(balance, Amount::zero(), Amount::zero()).unwrap(),
+        keys.into(),
+    ));
+
+    let users_module = module_load_v1_raw("tests/overlay-users-mock.wasm").unwrap();
+    let users_module = chain
+        .module_deploy_v1(Signer::with_one_key(), ADMIN, users_module)
+        .unwrap();
+    let users = chain
+        .contract_init(
+            Signer::with_one_key(),
+            ADMIN,
+            ENERGY,
+            InitContractPayload {
+                amount: Amount::zero(),
+                mod_ref: users_module.module_reference,
+                init_name: OwnedContractName::new_unchecked("init_overlay-users".to_string()),
+                param: OwnedParameter::empty(),
+            },
+        )
+        .unwrap();
+
+    let projects_module = module_load_v1(PROJECTS_MODULE).unwrap();
+    let projects_module = chain
+        .module_deploy_v1(Signer::with_one_key(), ADMIN, projects_module)
+        .unwrap();
+    let projects = chain
+        .contract_init(
+            Signer::with_one_key(),
+            ADMIN,
+            ENERGY,
+            InitContractPayload {
+                amount: Amount::zero(),
+                mod_ref: projects_module.module_reference,
+                init_name: OwnedContractName::new_unchecked("init_overlay-projects".to_string()),
+                param: OwnedParameter::from_serial(&InitParams {
+                    staking_contract_addr: ContractAddress::new(1000, 0),
+                    user_contract_addr: users.contract_address,
+                })
+                .unwrap(),
+            },
+        )
+        .unwrap();
+    update(
+        &mut chain,
+        ADMIN,
+        projects.contract_address,
+        "curate_project_admin",
+        OwnedParameter::from_serial(&CurateProjectAdminParams {
+            curator: ADMIN,
+            project_id: Some(PROJECT_ID.into()),
+            project_uri: ProjectUri {
+                url: "https://overlay.global/".into(),
+                hash: Some([0; 32]),
+            },
+            owners: vec![SIGNER],
+        })
+        .unwrap(),
+    )
+    .unwrap();
+    Some((chain, projects.contract_address))
+}
+
+fn update(
+    chain: &mut Chain,
+    invoker: AccountAddress,
+    address: ContractAddress,
+    entrypoint: &str,
+    message: OwnedParameter,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    chain.contract_update(
+        Signer::with_one_key(),
+        invoker,
+        Address::Account(invoker),
+        ENERGY,
+        UpdateContractPayload {
+            amount: Amount::zero(),
+            address,
+            receive_name: OwnedReceiveName::new_unchecked(format!(
+                "overlay-projects.{}",
+                entrypoint
+            )),
+            message,
+        },
+    )
+}
+
+/// Build a message for `add_token_addr` of the curated project.
+fn add_token_addr_message(projects: ContractAddress, nonce: u64) -> PermitMessage {
+    PermitMessage {
+        contract_address: projects,
+        nonce,
+        timestamp: Timestamp::from_timestamp_millis(200),
+        entry_point: OwnedEntrypointName::new_unchecked("add_token_addr".to_string()),
+        payload: to_bytes(&AddTokenAddrParams {
+            project_id: PROJECT_ID.into(),
+            token_addr: ContractAddress::new(2000, nonce),
+        }),
+    }
+}
+
+/// Hash of the inputted bytes prepended with the signer address and 8 zero bytes as defined in
+/// CIS-3 standard.
+fn message_hash(signer: AccountAddress, message_bytes: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(signer.0)
+        .chain_update([0u8; 8])
+        .chain_update(message_bytes)
+        .finalize()
+        .into()
+}
+
+fn permit(
+    chain: &mut Chain,
+    projects: ContractAddress,
+    signature: AccountSignatures,
+    message: PermitMessage,
+) -> Result<ContractInvokeSuccess, ContractInvokeError> {
+    update(
+        chain,
+        SPONSOR,
+        projects,
+        "permit",
+        OwnedParameter::from_serial(&PermitParams {
+            signature,
+            signer: SIGNER,
+            message,
+        })
+        .unwrap(),
+    )
+}
+
+fn nonce_of_signer(chain: &Chain, projects: ContractAddress) -> u64 {
+    let result = chain
+        .contract_invoke(
+            SPONSOR,
+            Address::Account(SPONSOR),
+            ENERGY,
+            UpdateContractPayload {
+                amount: Amount::zero(),
+                address: projects,
+                receive_name: OwnedReceiveName::new_unchecked(
+                    "overlay-projects.nonceOf".to_string(),
+                ),
+                message: OwnedParameter::from_serial(&NonceOfParams {
+                    queries: vec![SIGNER],
+                })
+                .unwrap(),
+            },
+        )
+        .unwrap();
+    let response: NonceOfResponse = from_bytes(&result.return_value).unwrap();
+    response.nonces[0]
+}
+
+/// Test that overlay-projects.permit accepts messages signed by the signer account, increments
+/// its nonce and logs the nonce events.
+#[test]
+fn test_permit() {
+    let keys = AccountKeys::singleton(&mut rand::thread_rng());
+    let Some((mut chain, projects)) = setup_chain(&keys) else {
+        return;
+    };
+
+    for nonce in 0..2 {
+        let message = add_token_addr_message(projects, nonce);
+        let signature = keys.sign_message(&message_hash(SIGNER, &to_bytes(&message)));
+        let result = permit(&mut chain, projects, signature, message).unwrap();
+        let events: Vec<NonceEvent> = result
+            .events()
+            .flat_map(|(_, events)| events.iter())
+            .map(|event| event.parse().unwrap())
+            .collect();
+        assert_eq!(
+            events,
+            vec![NonceEvent {
+                tag: 250,
+                nonce,
+                sponsoree: SIGNER,
+            }]
+        );
+        assert_eq!(nonce_of_signer(&chain, projects), nonce + 1);
+    }
+}
+
+/// Test that overlay-projects.permit rejects messages which are not signed by the signer account
+/// over the CIS-3 message hash.
+#[test]
+fn test_permit_invalid_signature() {
+    let keys = AccountKeys::singleton(&mut rand::thread_rng());
+    let Some((mut chain, projects)) = setup_chain(&keys) else {
+        return;
+    };
+    let message = add_token_addr_message(projects, 0);
+
+    // signed by another account.
+    let other_keys = AccountKeys::singleton(&mut rand::thread_rng());
+    let signature = other_keys.sign_message(&message_hash(SIGNER, &to_bytes(&message)));
+    let result = permit(&mut chain, projects, signature, message.clone());
+    assert_eq!(
+        result.unwrap_err().return_value(),
+        Some(&[INVALID_SIGNATURE][..])
+    );
+
+    // signed over the message without the CIS-3 prepend.
+    let signature = keys.sign_message(&Sha256::digest(to_bytes(&message)));
+    let result = permit(&mut chain, projects, signature, message);
+    assert_eq!(
+        result.unwrap_err().return_value(),
+        Some(&[INVALID_SIGNATURE][..])
+    );
+
+    assert_eq!(nonce_of_signer(&chain, projects), 0);
+}